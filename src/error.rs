@@ -24,4 +24,28 @@ pub enum Error {
     /// A required asset directory was not found inside the embedded assets.
     #[error("{0} does not exists")]
     MissingDirectory(String),
+    /// A line of pasted Showdown text could not be parsed.
+    #[error("Line {0} is not valid Showdown text: {1}")]
+    InvalidShowdownLine(usize, String),
+    /// The species does not exist in the active game.
+    #[error("{0} is not a species in this game")]
+    UnknownSpecies(String),
+    /// The move does not exist in the active game.
+    #[error("{0} is not a move in this game")]
+    UnknownMove(String),
+    /// The item does not exist in the active game or can't be held.
+    #[error("{0} is not a held item in this game")]
+    UnknownItem(String),
+    /// The nature name is not one of the 25 natures.
+    #[error("{0} is not a nature")]
+    UnknownNature(String),
+    /// The nickname is too long or uses characters the game can't display.
+    #[error("{0} can't be used as a nickname in this game")]
+    InvalidNickname(String),
+    /// There are not enough empty slots left to paste a team into.
+    #[error("No empty slot left for {0}")]
+    NoEmptySlot(String),
+    /// `pk_edit` rejected a value.
+    #[error("{0}")]
+    Pokemon(String),
 }
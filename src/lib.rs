@@ -3,6 +3,7 @@ pub mod icon;
 pub mod message;
 pub mod misc;
 pub mod screen;
+pub mod showdown;
 pub mod theme;
 pub mod widgets;
 
@@ -14,13 +15,12 @@ pub use widgets::*;
 
 use iced::widget::image;
 use iced::Point;
-use pk_edit::StorageType;
+use pk_edit::SlotRef;
 
 /// Holds the state of an in-progress drag operation.
 #[derive(Debug)]
 pub struct DragState {
-    pub storage: StorageType,
+    pub slot: SlotRef,
     pub cursor: Point,
     pub handle: image::Handle,
-    pub index: usize,
 }
@@ -15,15 +15,21 @@ use std::sync::Arc;
 use tracing_subscriber;
 
 use pk_editor::error::Error;
+use pk_editor::events::Events;
 use pk_editor::message::Message;
 use pk_editor::misc::{PROJECT_DIR, WINDOW_HEIGHT, WINDOW_WIDTH};
 use pk_editor::DragState;
-use pk_editor::{bag, icon, party_box};
+use pk_editor::{bag, daycare, events, hall_of_fame, icon, party_box, pokedex, showdown};
 
+use pk_edit::legality::{self, LegalityReport};
 use pk_edit::misc::extract_db;
-use pk_edit::{AnyPokemon, GameData, Gen3Pocket as Pocket, OpenSave, PokemonTrait, StorageType};
+use pk_edit::{
+    AnyPokemon, DayCare, EvolutionMethod, GameData, Gen3Pocket as Pocket, HallOfFame, Location,
+    OpenSave, Pokedex, PokemonFactoryTrait, PokemonTrait, Roamer, SlotRef, Trainer,
+};
 use pk_editor::menu_bar;
 use pk_editor::pokemon_info;
+use pk_editor::roamer;
 
 fn main() -> iced::Result {
     tracing_subscriber::fmt()
@@ -55,15 +61,26 @@ pub struct State {
     current_pc_index: usize,
     selected_tab: Option<Id>,
     selected_bag: Option<Id>,
+    trainer: Option<Trainer>,
+    trainer_name_input: Option<String>,
+    pokedex: Option<Pokedex>,
+    events: Option<Events>,
+    hall_of_fame: Option<HallOfFame>,
+    daycare: Option<DayCare>,
+    daycare_parents: Vec<AnyPokemon>,
+    roamer: Option<Roamer>,
     current_pc: Vec<AnyPokemon>,
     selected_pokemon: Option<AnyPokemon>,
+    legality: Option<LegalityReport>,
     tm_bag: Vec<(String, u16)>,
     key_bag: Vec<(String, u16)>,
     item_bag: Vec<(String, u16)>,
     ball_bag: Vec<(String, u16)>,
     berry_bag: Vec<(String, u16)>,
-    selected_pokemon_storage: StorageType,
+    selected_slot: Option<SlotRef>,
     cb_state: iced::widget::combo_box::State<String>,
+    legal_mode: pokemon_info::LegalMode,
+    experience_input: Option<String>,
     images: HashMap<String, image::Handle>,
 }
 
@@ -71,6 +88,9 @@ pub struct State {
 pub enum Screen {
     PartyBoxes,
     BagTrainer,
+    Pokedex,
+    Events,
+    HallOfFame,
 }
 
 impl State {
@@ -88,15 +108,26 @@ impl State {
                 show_modal: false,
                 current_pc_index: 0,
                 selected_pokemon: None,
+                legality: None,
                 theme: iced::Theme::Dracula,
                 save_file: None,
                 selected_tab: Some(Id::new("1")),
                 selected_bag: Some(Id::new("1")),
+                trainer: None,
+                trainer_name_input: None,
+                pokedex: None,
+                events: None,
+                hall_of_fame: None,
+                daycare: None,
+                daycare_parents: vec![],
+                roamer: None,
                 screen: Some(Screen::PartyBoxes),
                 party: vec![],
                 current_pc: vec![],
-                selected_pokemon_storage: StorageType::None,
+                selected_slot: None,
                 cb_state: iced::widget::combo_box::State::new(vec![]),
+                legal_mode: pokemon_info::LegalMode::default(),
+                experience_input: None,
                 images: HashMap::new(),
             },
             Task::perform(load_images(), Message::ImagesListed),
@@ -131,11 +162,18 @@ impl State {
                 }
                 menu_bar::Message::SelectedTab(id) => {
                     self.selected_tab = Some(id);
+                    self.trainer_name_input = None;
 
                     if self.selected_tab == Some(Id::new("1")) {
                         self.screen = Some(Screen::PartyBoxes);
                     } else if self.selected_tab == Some(Id::new("2")) {
                         self.screen = Some(Screen::BagTrainer);
+                    } else if self.selected_tab == Some(Id::new("3")) {
+                        self.screen = Some(Screen::Pokedex);
+                    } else if self.selected_tab == Some(Id::new("4")) {
+                        self.screen = Some(Screen::Events);
+                    } else if self.selected_tab == Some(Id::new("5")) {
+                        self.screen = Some(Screen::HallOfFame);
                     }
 
                     Task::none()
@@ -151,6 +189,7 @@ impl State {
                         &mut self.berry_bag,
                         gen3,
                         &mut self.selected_bag,
+                        &mut self.trainer_name_input,
                         message,
                     ) {
                         let error_msg = error.to_string();
@@ -159,6 +198,76 @@ impl State {
                 }
                 self.update(Message::UpdateChanges)
             }
+            Message::Pokedex(message) => {
+                if let (Some(OpenSave::Gen3(gen3)), Some(pokedex)) =
+                    (&mut self.save_file, &mut self.pokedex)
+                {
+                    if let Err(error) = pokedex::update(pokedex, gen3, message) {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
+                }
+                self.update(Message::UpdateChanges)
+            }
+            Message::Events(message) => {
+                if let (Some(OpenSave::Gen3(gen3)), Some(events)) =
+                    (&mut self.save_file, &mut self.events)
+                {
+                    if let Err(error) = events::update(events, gen3, message) {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
+                }
+                // Installing a Mystery Event adds its ticket to the key items pocket.
+                self.update(Message::UpdateChanges)
+            }
+            Message::HallOfFame(message) => {
+                if let (Some(OpenSave::Gen3(gen3)), Some(hall_of_fame)) =
+                    (&mut self.save_file, &mut self.hall_of_fame)
+                {
+                    if let Err(error) =
+                        hall_of_fame::update(hall_of_fame, gen3, &self.party, message)
+                    {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
+                }
+                // Reloading from the save would fill a nickname that is still being typed.
+                let edited = self.hall_of_fame.clone();
+                let task = self.update(Message::UpdateChanges);
+                self.hall_of_fame = edited;
+                task
+            }
+            Message::DayCare(message) => {
+                if let (Some(OpenSave::Gen3(gen3)), Some(daycare)) =
+                    (&mut self.save_file, &mut self.daycare)
+                {
+                    if let Err(error) =
+                        daycare::update(daycare, gen3, &self.daycare_parents, message)
+                    {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
+                }
+                self.update(Message::UpdateChanges)
+            }
+            Message::Roamer(message) => {
+                if let (Some(OpenSave::Gen3(gen3)), Some(roamer)) =
+                    (&mut self.save_file, &mut self.roamer)
+                {
+                    // Reactivating or resetting the roamer also updates its story flags and vars.
+                    let result = roamer::update(roamer, gen3, message).and_then(|()| {
+                        self.events
+                            .as_mut()
+                            .map_or(Ok(()), |events| events.reload(gen3))
+                    });
+                    if let Err(error) = result {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
+                }
+                self.update(Message::UpdateChanges)
+            }
             Message::PokemonInfo(message) => {
                 if let Some(ref save_file) = self.save_file {
                     let factory = save_file.pokemon_factory();
@@ -167,6 +276,8 @@ impl State {
                     let ot_id = save_file.trainer_id();
                     if let Err(error) = pokemon_info::update(
                         &mut self.selected_pokemon,
+                        &mut self.legal_mode,
+                        &mut self.experience_input,
                         &factory,
                         &game_data,
                         &ot_name,
@@ -215,14 +326,34 @@ impl State {
                 self.item_bag = vec![];
                 self.ball_bag = vec![];
                 self.berry_bag = vec![];
+                self.trainer = None;
+                self.trainer_name_input = None;
+                self.pokedex = None;
+                self.events = None;
+                self.hall_of_fame = None;
+                self.daycare = None;
+                self.daycare_parents = vec![];
+                self.roamer = None;
                 self.current_pc_index = 0;
                 self.selected_pokemon = None;
-                self.selected_pokemon_storage = StorageType::None;
+                self.experience_input = None;
+                self.legality = None;
+                self.selected_slot = None;
 
                 match pk_edit::open(&results) {
                     Ok(save_file) => {
                         let species = save_file.game_data().species().unwrap_or_default();
                         self.cb_state = iced::widget::combo_box::State::new(species);
+                        if let OpenSave::Gen3(ref gen3) = save_file {
+                            self.events = Events::load(gen3).ok();
+                            self.roamer = gen3.roamer().ok();
+                            let version = gen3.get_game_version().ok();
+                            // Location IDs mean different places in another game.
+                            if self.legal_mode.version != version {
+                                self.legal_mode.met_location = None;
+                            }
+                            self.legal_mode.version = version;
+                        }
                         self.save_file = Some(save_file);
                     }
                     Err(error) => {
@@ -237,6 +368,236 @@ impl State {
                 let error_msg = error.to_string();
                 Task::perform(save_error_dialog(error_msg), |_| Message::HideModal)
             }
+            Message::ImportPokemon => {
+                self.show_modal = true;
+                Task::perform(pick_pokemon_file(), Message::PokemonFileOpened)
+            }
+            Message::ExportPokemon => {
+                let Some(ref selected_pokemon) = self.selected_pokemon else {
+                    return Task::none();
+                };
+                self.show_modal = true;
+                Task::perform(
+                    save_pokemon_file(format!("{}.pk3", export_file_stem(selected_pokemon))),
+                    Message::PokemonFileSaved,
+                )
+            }
+            Message::PokemonFileOpened(Ok(path)) => {
+                Task::perform(load_file(path), Message::LoadPokemon)
+            }
+            Message::PokemonFileOpened(Err(error)) | Message::PokemonFileSaved(Err(error)) => {
+                match error {
+                    Error::DialogClosed => self.show_modal = false,
+                    _ => {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
+                }
+                Task::none()
+            }
+            Message::PokemonFileSaved(Ok(path)) => {
+                let Some(ref selected_pokemon) = self.selected_pokemon else {
+                    return Task::none();
+                };
+                let bytes = if path.extension().is_some_and(|ext| ext == "ek3") {
+                    selected_pokemon.ek_bytes()
+                } else {
+                    selected_pokemon.pk_bytes()
+                };
+                Task::perform(write_file(path, Some(Arc::new(bytes))), Message::WriteFile)
+            }
+            Message::LoadPokemon(Ok(bytes)) => {
+                self.show_modal = false;
+                let (Some(save_file), Some(selected_pokemon)) =
+                    (&self.save_file, &self.selected_pokemon)
+                else {
+                    return Task::none();
+                };
+                let imported = save_file
+                    .pokemon_factory()
+                    .pokemon_from_bytes(selected_pokemon, &bytes);
+                match imported {
+                    Ok(pokemon) => {
+                        self.selected_pokemon = Some(pokemon);
+                        self.experience_input = None;
+                        self.update(Message::UpdateChanges)
+                    }
+                    Err(error) => {
+                        let error_msg = error.to_string();
+                        Task::perform(save_error_dialog(error_msg), |_| Message::HideModal)
+                    }
+                }
+            }
+            Message::LoadPokemon(Err(error)) => {
+                let error_msg = error.to_string();
+                Task::perform(save_error_dialog(error_msg), |_| Message::HideModal)
+            }
+            Message::CopyShowdown => match (&self.save_file, &self.selected_pokemon) {
+                (Some(save_file), Some(selected_pokemon)) if !selected_pokemon.is_empty() => {
+                    let game_data = save_file.game_data();
+                    iced::clipboard::write(showdown::to_showdown(selected_pokemon, &game_data))
+                }
+                _ => Task::none(),
+            },
+            Message::PasteShowdown(location) => {
+                iced::clipboard::read().map(move |text| Message::ShowdownPasted(location, text))
+            }
+            Message::ShowdownPasted(location, Some(text)) => {
+                let Some(ref mut save_file) = self.save_file else {
+                    return Task::none();
+                };
+
+                let sets = match showdown::parse_team(&text) {
+                    Ok(sets) => sets,
+                    Err(error) => {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
+                };
+
+                // The pasted Pokémon land in empty slots, drop the selection so
+                // `UpdateChanges` doesn't write a stale empty slot over them.
+                self.selected = None;
+                self.selected_pokemon = None;
+
+                let factory = save_file.pokemon_factory();
+                let game_data = save_file.game_data();
+                let ot_name = save_file.trainer_name();
+                let ot_id = save_file.trainer_id();
+                // Read the slots from the save, the shown box may have changed while
+                // the clipboard was being read.
+                let slots = match location {
+                    Location::Party => save_file.party(),
+                    Location::Box(index) => save_file.pc_box(index),
+                    Location::DayCare => save_file.daycare(),
+                };
+                let slots = match slots {
+                    Ok(slots) => slots,
+                    Err(error) => {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
+                };
+                let mut empty_slots = slots
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, pokemon)| pokemon.is_empty());
+
+                for set in &sets {
+                    let result = empty_slots
+                        .next()
+                        .ok_or_else(|| Error::NoEmptySlot(set.species.clone()))
+                        .and_then(|(index, slot)| {
+                            showdown::build(set, slot, &factory, &game_data, &ot_name, ot_id)
+                                .map(|pokemon| (SlotRef { location, index }, pokemon))
+                        })
+                        .and_then(|(slot, pokemon)| {
+                            save_file
+                                .save_pokemon(slot, pokemon)
+                                .map_err(|error| Error::Pokemon(error.to_string()))
+                        });
+                    if let Err(error) = result {
+                        let error_msg = error.to_string();
+                        return Task::batch([
+                            self.update(Message::UpdateChanges),
+                            Task::perform(save_error_dialog(error_msg), |_| Message::HideModal),
+                        ]);
+                    }
+                }
+
+                self.update(Message::UpdateChanges)
+            }
+            Message::ShowdownPasted(_, None) => Task::none(),
+            Message::HealParty => {
+                let Some(ref mut save_file) = self.save_file else {
+                    return Task::none();
+                };
+
+                // Heal the selection in place so `UpdateChanges` doesn't write it back unhealed.
+                if self
+                    .selected_slot
+                    .is_some_and(|slot| slot.location == Location::Party)
+                {
+                    if let Some(selected_pokemon) = self.selected_pokemon.as_mut() {
+                        selected_pokemon.heal();
+                    }
+                }
+                for (index, pokemon) in self.party.iter_mut().enumerate() {
+                    if pokemon.is_empty() {
+                        continue;
+                    }
+                    pokemon.heal();
+                    pokemon.update_checksum();
+                    let slot = SlotRef {
+                        location: Location::Party,
+                        index,
+                    };
+                    if let Err(error) = save_file.save_pokemon(slot, *pokemon) {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
+                }
+
+                self.update(Message::UpdateChanges)
+            }
+            Message::Evolve(species) => {
+                let (Some(save_file), Some(mut pokemon)) =
+                    (&mut self.save_file, self.selected_pokemon)
+                else {
+                    return Task::none();
+                };
+
+                let game_data = save_file.game_data();
+                let sheds = game_data
+                    .evolution_chain(pokemon.nat_dex_number())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .any(|evolution| {
+                        evolution.from == pokemon.species()
+                            && matches!(evolution.method, EvolutionMethod::Shed(_))
+                    });
+
+                if let Err(error) = pokemon.evolve_to(&species) {
+                    let error_msg = error.to_string();
+                    return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                }
+                self.selected_pokemon = Some(pokemon);
+                self.experience_input = None;
+
+                // Like in-game, Nincada leaves a Shedinja behind in the first empty party slot.
+                let in_party = self
+                    .selected_slot
+                    .is_some_and(|slot| slot.location == Location::Party);
+                if sheds && in_party {
+                    if let Some((index, slot)) = self
+                        .party
+                        .iter()
+                        .enumerate()
+                        .find(|(_, slot)| slot.is_empty())
+                    {
+                        let factory = save_file.pokemon_factory();
+                        let result = factory
+                            .gen_shedinja(slot, &pokemon)
+                            .map_err(|error| error.to_string())
+                            .and_then(|shedinja| {
+                                let slot = SlotRef {
+                                    location: Location::Party,
+                                    index,
+                                };
+                                save_file
+                                    .save_pokemon(slot, shedinja)
+                                    .map_err(|error| error.to_string())
+                            });
+                        if let Err(error_msg) = result {
+                            return Task::perform(save_error_dialog(error_msg), |_| {
+                                Message::HideModal
+                            });
+                        }
+                    }
+                }
+
+                self.update(Message::UpdateChanges)
+            }
             Message::WriteFile(Ok(_)) => {
                 Task::perform(save_success_dialog(), |_| Message::HideModal)
             }
@@ -249,11 +610,16 @@ impl State {
                     return Task::none();
                 };
 
-                if let Some(mut selected_pokemon) = self.selected_pokemon {
+                if let (Some(mut selected_pokemon), Some(slot)) =
+                    (self.selected_pokemon, self.selected_slot)
+                {
+                    // The panel keeps a cleared nickname while typing, the save gets the species name.
+                    if let Err(error) = pokemon_info::fill_empty_nickname(&mut selected_pokemon) {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
+                    }
                     selected_pokemon.update_checksum();
-                    if let Err(error) = save_file
-                        .save_pokemon(self.selected_pokemon_storage, selected_pokemon)
-                    {
+                    if let Err(error) = save_file.save_pokemon(slot, selected_pokemon) {
                         let error_msg = error.to_string();
                         return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
                     }
@@ -261,51 +627,71 @@ impl State {
 
                 self.party = save_file.party().unwrap_or_default();
                 self.current_pc = save_file.pc_box(self.current_pc_index).unwrap_or_default();
+                self.daycare_parents = save_file.daycare().unwrap_or_default();
                 self.item_bag = save_file.pocket(Pocket::Items).unwrap_or_default();
                 self.ball_bag = save_file.pocket(Pocket::Pokeballs).unwrap_or_default();
                 self.berry_bag = save_file.pocket(Pocket::Berries).unwrap_or_default();
                 self.tm_bag = save_file.pocket(Pocket::Tms).unwrap_or_default();
                 self.key_bag = save_file.pocket(Pocket::Key).unwrap_or_default();
+                (self.trainer, self.pokedex, self.hall_of_fame, self.daycare) =
+                    if let OpenSave::Gen3(gen3) = save_file {
+                        (
+                            gen3.get_trainer().ok(),
+                            gen3.pokedex().ok(),
+                            gen3.hall_of_fame().ok(),
+                            gen3.daycare().ok(),
+                        )
+                    } else {
+                        (None, None, None, None)
+                    };
+                self.check_legality();
 
                 Task::none()
             }
-            Message::Selected(id, storage, pokemon) => {
+            Message::Selected(id, slot, pokemon) => {
                 self.selected = id;
-                if let Some(storage) = storage {
-                    self.selected_pokemon_storage = storage;
+                if slot.is_some() {
+                    self.selected_slot = slot;
                 }
                 self.selected_pokemon = pokemon;
+                self.experience_input = None;
+                self.check_legality();
                 Task::none()
             }
             Message::Increment => {
-                if self.save_file.as_ref().is_some_and(|s| !s.is_pc_empty()) {
-                    if self.current_pc_index < 13 {
-                        self.current_pc_index += 1;
-                    } else {
-                        self.current_pc_index = 0;
-                    }
+                // `None` when there is no box to wrap around to.
+                let next = self
+                    .save_file
+                    .as_ref()
+                    .filter(|s| !s.is_pc_empty())
+                    .and_then(|s| (self.current_pc_index + 1).checked_rem(s.box_count()));
+                if let Some(next) = next {
+                    self.current_pc_index = next;
                     self.update(Message::UpdateChanges)
                 } else {
                     Task::none()
                 }
             }
             Message::Decrement => {
-                if self.save_file.as_ref().is_some_and(|s| !s.is_pc_empty()) {
-                    if self.current_pc_index > 0 {
-                        self.current_pc_index -= 1;
-                    } else {
-                        self.current_pc_index = 13;
-                    }
+                if let Some(save_file) = self
+                    .save_file
+                    .as_ref()
+                    .filter(|s| !s.is_pc_empty() && s.box_count() > 0)
+                {
+                    self.current_pc_index = self
+                        .current_pc_index
+                        .checked_sub(1)
+                        .unwrap_or_else(|| save_file.box_count().saturating_sub(1));
                     self.update(Message::UpdateChanges)
                 } else {
                     Task::none()
                 }
             }
-            Message::DragStart(storage, origin, nat_dex_number, i) => {
-                tracing::debug!(?storage, i, "DragStart");
+            Message::DragStart(slot, origin, sprite) => {
+                tracing::debug!(?slot, "DragStart");
                 let handle = self
                     .images
-                    .get(&format!("{:0width$}", nat_dex_number, width = 4))
+                    .get(&sprite)
                     .unwrap_or({
                         let width = 10;
                         let height = 10;
@@ -316,10 +702,9 @@ impl State {
                     .clone();
 
                 self.drag = Some(DragState {
-                    storage,
+                    slot,
                     cursor: origin,
                     handle,
-                    index: i,
                 });
                 Task::none()
             }
@@ -329,36 +714,22 @@ impl State {
                 }
                 Task::none()
             }
-            Message::DragDrop(to_storage, to_index) => {
-                tracing::debug!(?to_storage, to_index, "DragDrop");
-                if let Some(from) = self.drag.take() {
-                    let from_pokemon = match from.storage {
-                        StorageType::PC => self.current_pc.get(from.index).copied(),
-                        StorageType::Party => self.party.get(from.index).copied(),
-                        StorageType::None => None,
-                    };
-                    let to_pokemon = match to_storage {
-                        StorageType::PC => self.current_pc.get(to_index).copied(),
-                        StorageType::Party => self.party.get(to_index).copied(),
-                        StorageType::None => None,
-                    };
-                    if let (Some(from_pokemon), Some(to_pokemon), Some(ref mut save_file)) =
-                        (from_pokemon, to_pokemon, self.save_file.as_mut())
-                    {
-                        if let Err(error) = save_file.swap_pokemon(
-                            from_pokemon,
-                            from.storage,
-                            to_pokemon,
-                            to_storage,
-                        ) {
-                            let error_msg = error.to_string();
-                            return Task::perform(
-                                save_error_dialog(error_msg),
-                                |_| Message::HideModal,
-                            );
-                        }
-                        return self.update(Message::UpdateChanges);
+            Message::DragDrop(to) => {
+                tracing::debug!(?to, "DragDrop");
+                if let (Some(from), Some(save_file)) = (self.drag.take(), self.save_file.as_mut()) {
+                    // Slots are addressed by location, so this also moves between boxes.
+                    if let Err(error) = save_file.swap_pokemon(from.slot, to) {
+                        let error_msg = error.to_string();
+                        return Task::perform(save_error_dialog(error_msg), |_| Message::HideModal);
                     }
+                    // The selected slot may now hold the other Pokémon, drop the selection
+                    // so `UpdateChanges` doesn't write the moved one back over it.
+                    self.selected = None;
+                    self.selected_pokemon = None;
+                    self.selected_slot = None;
+                    self.experience_input = None;
+                    self.legality = None;
+                    return self.update(Message::UpdateChanges);
                 }
                 Task::none()
             }
@@ -387,19 +758,28 @@ impl State {
             .map(|s| s.game_data())
             .unwrap_or_default();
 
+        // Boxes are `(columns, rows)` slots big, which differs between games.
+        let box_size = self.save_file.as_ref().map_or((0, 0), |s| s.box_size());
+
         let scale = (WINDOW_WIDTH + 50.0) / 1130.0;
         let scale = scale.max(1.0);
 
         let content = container(match self.screen {
             Some(Screen::PartyBoxes) => party_box(
                 &self.cb_state,
+                &self.legal_mode,
+                &self.experience_input,
                 &self.selected,
                 &self.selected_tab,
                 &self.selected_pokemon,
+                self.legality.as_ref(),
                 &game_data,
                 &self.party,
                 &self.current_pc_index,
+                box_size,
                 &self.current_pc,
+                &self.daycare_parents,
+                &self.daycare,
                 &self.images,
                 &self.drag,
                 scale,
@@ -407,6 +787,8 @@ impl State {
             Some(Screen::BagTrainer) => bag(
                 &self.selected_bag,
                 &self.selected_tab,
+                &self.trainer,
+                &self.trainer_name_input,
                 &self.item_bag,
                 &self.ball_bag,
                 &self.berry_bag,
@@ -414,6 +796,18 @@ impl State {
                 &self.key_bag,
                 &self.images,
             ),
+            Some(Screen::Pokedex) => {
+                pokedex(&self.selected_tab, &self.pokedex, &game_data, &self.images)
+            }
+            Some(Screen::Events) => {
+                events(&self.selected_tab, &self.events, &self.roamer, &self.images)
+            }
+            Some(Screen::HallOfFame) => hall_of_fame(
+                &self.selected_tab,
+                &self.hall_of_fame,
+                &self.party,
+                &self.images,
+            ),
             _ => container("").into(),
         });
 
@@ -475,10 +869,20 @@ impl State {
             Subscription::none()
         }
     }
+
+    /// Re-runs the legality checks for the selected Pokémon, so `view` only renders the stored report.
+    fn check_legality(&mut self) {
+        self.legality = match (&self.save_file, &self.selected_pokemon) {
+            (Some(save_file), Some(pokemon)) => {
+                Some(legality::check(pokemon, &save_file.game_data()))
+            }
+            _ => None,
+        };
+    }
 }
 
 async fn load_images() -> Result<HashMap<String, image::Handle>, Error> {
-    let folders = ["icons", "Items", "Pokemon", "Types"];
+    let folders = ["icons", "Items", "Pokemon", "Ribbons", "Types"];
     let mut images: HashMap<String, image::Handle> = HashMap::new();
     for folder in folders {
         let dir = PROJECT_DIR
@@ -536,6 +940,51 @@ async fn save_file() -> Result<PathBuf, Error> {
     Ok(handle.path().to_owned())
 }
 
+async fn pick_pokemon_file() -> Result<PathBuf, Error> {
+    let handle = rfd::AsyncFileDialog::new()
+        .set_title("Import a Pokémon...")
+        .add_filter("Pokémon File", &["pk3", "ek3"])
+        .pick_file()
+        .await
+        .ok_or(Error::DialogClosed)?;
+
+    Ok(handle.path().to_owned())
+}
+
+/// The nickname with the characters file systems reject replaced, or the
+/// species name when nothing usable is left.
+fn export_file_stem(pokemon: &AnyPokemon) -> String {
+    let nickname: String = pokemon
+        .nickname()
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+    let nickname = nickname.trim().trim_end_matches('.');
+
+    if nickname.chars().all(|c| c == '_' || c == '.') {
+        pokemon.species()
+    } else {
+        nickname.to_owned()
+    }
+}
+
+async fn save_pokemon_file(file_name: String) -> Result<PathBuf, Error> {
+    let handle = rfd::AsyncFileDialog::new()
+        .set_title("Export a Pokémon...")
+        .add_filter("Decrypted Pokémon File", &["pk3"])
+        .add_filter("Encrypted Pokémon File", &["ek3"])
+        .set_file_name(file_name)
+        .save_file()
+        .await
+        .ok_or(Error::DialogClosed)?;
+
+    Ok(handle.path().to_owned())
+}
+
 async fn load_file(path: PathBuf) -> Result<Arc<Vec<u8>>, Error> {
     let contents = tokio::fs::read(&path)
         .await
@@ -11,13 +11,17 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use pk_edit::AnyPokemon;
-use pk_edit::StorageType;
+use pk_edit::{AnyPokemon, Location, SlotRef};
 
 use crate::bag;
+use crate::daycare;
 use crate::error::Error;
+use crate::events;
+use crate::hall_of_fame;
 use crate::menu_bar;
+use crate::pokedex;
 use crate::pokemon_info;
+use crate::roamer;
 
 /// The root message enum for all UI events and async task results.
 #[derive(Debug, Clone)]
@@ -34,6 +38,16 @@ pub enum Message {
     UpdateChanges,
     /// Delegate a bag-screen event to [`bag::update`].
     Bag(bag::Message),
+    /// Delegate a Pokédex-screen event to [`pokedex::update`].
+    Pokedex(pokedex::Message),
+    /// Delegate an events-screen event to [`events::update`].
+    Events(events::Message),
+    /// Delegate a Hall of Fame-screen event to [`hall_of_fame::update`].
+    HallOfFame(hall_of_fame::Message),
+    /// Delegate a Day Care counter or egg-flag edit to [`daycare::update`].
+    DayCare(daycare::Message),
+    /// Delegate a roaming legendary edit to [`roamer::update`].
+    Roamer(roamer::Message),
     /// Delegate a menu-bar event (open, save, tab switch) to the menu bar handler.
     MenuBar(menu_bar::Message),
     /// Delegate a Pokémon-info panel event to [`pokemon_info::update`].
@@ -48,14 +62,34 @@ pub enum Message {
     FileOpened(Result<PathBuf, Error>),
     /// Result of reading the raw bytes of a save file from disk.
     LoadFile(Result<Arc<Vec<u8>>, Error>),
-    /// A Pokémon slot was selected. Carries the widget [`Id`], [`StorageType`], and the [`AnyPokemon`].
-    Selected(Option<Id>, Option<StorageType>, Option<AnyPokemon>),
+    /// Open the file dialog to import a `.pk3` / `.ek3` file into the selected slot.
+    ImportPokemon,
+    /// Open the file dialog to export the selected Pokémon as a `.pk3` / `.ek3` file.
+    ExportPokemon,
+    /// Result of the Pokémon import dialog (path chosen by the user).
+    PokemonFileOpened(Result<PathBuf, Error>),
+    /// Result of the Pokémon export dialog (path chosen by the user).
+    PokemonFileSaved(Result<PathBuf, Error>),
+    /// Result of reading the raw bytes of a Pokémon file from disk.
+    LoadPokemon(Result<Arc<Vec<u8>>, Error>),
+    /// Copy the selected Pokémon to the clipboard as a Showdown set.
+    CopyShowdown,
+    /// Read a Showdown team from the clipboard and paste it into the party or the current box.
+    PasteShowdown(Location),
+    /// Clipboard contents read for [`Message::PasteShowdown`], with the target location.
+    ShowdownPasted(Location, Option<String>),
+    /// Restore HP, PP and status of every party Pokémon, like a Pokémon Center.
+    HealParty,
+    /// Evolve (or devolve) the selected Pokémon into the given species.
+    Evolve(String),
+    /// A Pokémon slot was selected. Carries the widget [`Id`], the [`SlotRef`], and the [`AnyPokemon`].
+    Selected(Option<Id>, Option<SlotRef>, Option<AnyPokemon>),
     /// Result of loading all sprite and icon images from the embedded asset directory.
     ImagesListed(Result<HashMap<String, image::Handle>, Error>),
-    /// A drag gesture started on a slot. Carries storage type, cursor origin, National Dex number, and slot index.
-    DragStart(StorageType, Point, u16, usize),
+    /// A drag gesture started on a slot. Carries the slot, cursor origin, and sprite key.
+    DragStart(SlotRef, Point, String),
     DragMoved(Point),
     DragReleased,
-    /// A drag gesture was released on a target slot. Carries target storage type and target slot index.
-    DragDrop(StorageType, usize),
+    /// A drag gesture was released on a target slot.
+    DragDrop(SlotRef),
 }
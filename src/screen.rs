@@ -1,5 +1,11 @@
 pub mod bag;
+pub mod events;
+pub mod hall_of_fame;
 pub mod party_box;
+pub mod pokedex;
 
 pub use bag::bag;
+pub use events::events;
+pub use hall_of_fame::hall_of_fame;
 pub use party_box::party_box;
+pub use pokedex::pokedex;
@@ -1,27 +1,32 @@
 //! The **Bag & Trainer** screen.
 //!
-//! Renders five scrollable bag pockets — Items, Pokéballs, Berries, TMs, and
-//! Key Items — each row showing an item sprite, a pick-list for the item name,
+//! Renders a trainer panel — name, gender, public/secret ID, time played and
+//! money — next to five scrollable bag pockets — Items, Pokéballs, Berries, TMs,
+//! and Key Items — each row showing an item sprite, a pick-list for the item name,
 //! and an [`crate::widgets::item_counter`] for the quantity.
 //!
 //! Owns its own [`Message`] enum and [`update`] function which validate
-//! quantities (0–99) and write changes back into the [`pk_edit::SaveFile`].
+//! quantities (0–99) and trainer fields against the game's limits, and write
+//! changes back into the [`pk_edit::SaveFile`].
 
 use iced::advanced::widget::Id;
+use iced::alignment::Horizontal;
+use iced::color;
 use iced::widget::container;
 use iced::widget::image;
-use iced::widget::{column, pick_list, row, scrollable, text};
+use iced::widget::{column, mouse_area, pick_list, row, scrollable, text, text_input};
 use iced::{Alignment, Element, Length, Padding};
 use pk_edit::error::SaveDataError;
 
 use std::collections::HashMap;
 
 use crate::message;
-use crate::pick_list_default;
+use crate::widgets::gender;
 use crate::widgets::item_counter;
+use crate::{pick_list_default, text_input_default};
 use crate::{shadow_box_light, tab_bar_tab};
 
-use pk_edit::{Gen3GameData, Gen3Pocket as Pocket, Gen3SaveFile};
+use pk_edit::{Gen3GameData, Gen3Pocket as Pocket, Gen3SaveFile, Gender, Trainer};
 
 use crate::menu_bar;
 use crate::menu_bar_default;
@@ -32,6 +37,12 @@ pub enum Message {
     SelectedBag(Id),
     ItemChanged(Pocket, usize, String),
     ItemQuantityChanged(Pocket, usize, String, Operation),
+    TrainerNameChanged(String),
+    TrainerNameSubmitted,
+    TrainerGenderToggled,
+    TrainerIdChanged(IdPart, String),
+    TimePlayedChanged(TimeUnit, String),
+    MoneyChanged(String),
 }
 
 #[derive(Debug, Clone)]
@@ -41,6 +52,40 @@ pub enum Operation {
     Decrement,
 }
 
+/// Which half of the 32-bit trainer ID is being edited.
+#[derive(Debug, Clone, Copy)]
+pub enum IdPart {
+    Public,
+    Secret,
+}
+
+/// Which component of the play-time counter is being edited.
+#[derive(Debug, Clone, Copy)]
+pub enum TimeUnit {
+    Hours,
+    Minutes,
+    Seconds,
+    Frames,
+}
+
+const MAX_MONEY: u32 = 999_999;
+const MAX_HOURS: u32 = 999;
+const MAX_MINUTES: u32 = 59;
+const MAX_SECONDS: u32 = 59;
+const MAX_FRAMES: u32 = 59;
+
+/// Parses a numeric text input and clamps it to `max`. An empty input reads as `0`.
+fn parse_clamped(mut value: String, max: u32) -> Option<u32> {
+    value.retain(|c| c.is_ascii_digit());
+    if value.is_empty() {
+        return Some(0);
+    }
+    value
+        .parse::<u64>()
+        .ok()
+        .map(|number| u32::try_from(number.min(max.into())).unwrap_or(max))
+}
+
 pub fn update(
     tm_bag: &mut [(String, u16)],
     key_bag: &mut [(String, u16)],
@@ -49,6 +94,7 @@ pub fn update(
     berry_bag: &mut [(String, u16)],
     save_file: &mut Gen3SaveFile,
     selected_bag: &mut Option<Id>,
+    trainer_name_input: &mut Option<String>,
     message: Message,
 ) -> Result<(), SaveDataError> {
     match message {
@@ -110,12 +156,78 @@ pub fn update(
             }
             Ok(())
         }
+        Message::TrainerNameChanged(value) => {
+            let name: String = value
+                .chars()
+                .filter(|c| save_file.can_encode_char(*c))
+                .take(save_file.trainer_name_max_len())
+                .collect();
+            // The field may be emptied while typing, but the game refuses an empty trainer name.
+            let result = if name.is_empty() {
+                Ok(())
+            } else {
+                save_file.set_trainer_name(&name)
+            };
+            trainer_name_input.replace(name);
+            result
+        }
+        Message::TrainerNameSubmitted => {
+            // Dropping the edit buffer shows the saved name again, so an empty one is rejected.
+            trainer_name_input.take();
+            Ok(())
+        }
+        Message::TrainerGenderToggled => {
+            let gender = match save_file.get_trainer()?.gender {
+                Gender::M => Gender::F,
+                Gender::F | Gender::None => Gender::M,
+            };
+            save_file.set_trainer_gender(gender)
+        }
+        Message::TrainerIdChanged(part, value) => {
+            let Some(number) = parse_clamped(value, u16::MAX.into()) else {
+                return Ok(());
+            };
+            let number = u16::try_from(number).unwrap_or(u16::MAX);
+            let mut id = save_file.get_trainer()?.id;
+            match part {
+                IdPart::Public => id.public = number,
+                IdPart::Secret => id.private = number,
+            }
+            save_file.set_trainer_id(id)
+        }
+        Message::TimePlayedChanged(unit, value) => {
+            let max = match unit {
+                TimeUnit::Hours => MAX_HOURS,
+                TimeUnit::Minutes => MAX_MINUTES,
+                TimeUnit::Seconds => MAX_SECONDS,
+                TimeUnit::Frames => MAX_FRAMES,
+            };
+            let Some(number) = parse_clamped(value, max) else {
+                return Ok(());
+            };
+            let mut time_played = save_file.get_trainer()?.time_played;
+            match unit {
+                TimeUnit::Hours => time_played.hours = u16::try_from(number).unwrap_or(0),
+                TimeUnit::Minutes => time_played.minutes = u8::try_from(number).unwrap_or(0),
+                TimeUnit::Seconds => time_played.seconds = u8::try_from(number).unwrap_or(0),
+                TimeUnit::Frames => time_played.frames = u8::try_from(number).unwrap_or(0),
+            }
+            save_file.set_time_played(time_played)
+        }
+        Message::MoneyChanged(value) => {
+            if let Some(money) = parse_clamped(value, MAX_MONEY) {
+                save_file.set_money(money)?;
+            }
+            Ok(())
+        }
     }
 }
 
 pub fn bag<'a>(
     selected_bag: &Option<Id>,
     selected_tab: &Option<Id>,
+    trainer: &Option<Trainer>,
+    trainer_name_input: &Option<String>,
     items: &'a [(String, u16)],
     balls: &'a [(String, u16)],
     berries: &'a [(String, u16)],
@@ -132,6 +244,7 @@ pub fn bag<'a>(
         ],
         row![
             iced::widget::Space::new().width(Length::Fill),
+            trainer_panel(trainer, trainer_name_input).map(message::Message::Bag),
             if Some(Id::new("1")) == *selected_bag {
                 items_bag(items, images).map(message::Message::Bag)
             } else if Some(Id::new("2")) == *selected_bag {
@@ -153,6 +266,104 @@ pub fn bag<'a>(
     .into()
 }
 
+fn trainer_panel<'a>(
+    trainer: &Option<Trainer>,
+    trainer_name_input: &Option<String>,
+) -> Element<'a, Message> {
+    let Some(trainer) = trainer else {
+        return container("").width(350).into();
+    };
+    let label_width = 110;
+
+    let name = row![
+        text("Name").color(color!(0xffcc00)).width(label_width),
+        text_input(
+            &trainer.name,
+            trainer_name_input.as_deref().unwrap_or(&trainer.name)
+        )
+        .on_input(Message::TrainerNameChanged)
+        .on_paste(Message::TrainerNameChanged)
+        .on_submit(Message::TrainerNameSubmitted)
+        .style(text_input_default)
+        .width(120)
+        .size(14),
+        iced::widget::Space::new().width(Length::Fill),
+        mouse_area(gender(trainer.gender))
+            .interaction(iced::mouse::Interaction::Pointer)
+            .on_press(Message::TrainerGenderToggled),
+    ];
+
+    let public_id = trainer.id.public.to_string();
+    let secret_id = trainer.id.private.to_string();
+    let id = row![
+        text("ID No.").color(color!(0xffcc00)).width(label_width),
+        text_input(&public_id, &public_id)
+            .on_input(|input| Message::TrainerIdChanged(IdPart::Public, input))
+            .align_x(Horizontal::Center)
+            .style(text_input_default)
+            .width(70)
+            .size(14),
+        iced::widget::Space::new().width(Length::Fill),
+        text("SID").color(color!(0xffcc00)),
+        text_input(&secret_id, &secret_id)
+            .on_input(|input| Message::TrainerIdChanged(IdPart::Secret, input))
+            .align_x(Horizontal::Center)
+            .style(text_input_default)
+            .width(70)
+            .size(14),
+    ];
+
+    let time_played = &trainer.time_played;
+    let time = row![
+        text("Time played")
+            .color(color!(0xffcc00))
+            .width(label_width),
+        time_input(time_played.hours.to_string(), TimeUnit::Hours, 45.0),
+        text(":"),
+        time_input(time_played.minutes.to_string(), TimeUnit::Minutes, 35.0),
+        text(":"),
+        time_input(time_played.seconds.to_string(), TimeUnit::Seconds, 35.0),
+        text("."),
+        time_input(time_played.frames.to_string(), TimeUnit::Frames, 35.0),
+    ];
+
+    let money = trainer.money.to_string();
+    let money = row![
+        text("Money").color(color!(0xffcc00)).width(label_width),
+        text_input(&money, &money)
+            .on_input(Message::MoneyChanged)
+            .on_paste(Message::MoneyChanged)
+            .style(text_input_default)
+            .width(100)
+            .size(14),
+    ];
+
+    column([name, id, time, money].into_iter().map(|field| {
+        container(
+            field
+                .padding(Padding::from([0, 10]))
+                .align_y(Alignment::Center)
+                .spacing(5),
+        )
+        .style(shadow_box_light)
+        .width(350)
+        .height(40.0)
+        .into()
+    }))
+    .spacing(10)
+    .into()
+}
+
+fn time_input<'a>(value: String, unit: TimeUnit, width: f32) -> Element<'a, Message> {
+    text_input(&value, &value)
+        .on_input(move |input| Message::TimePlayedChanged(unit, input))
+        .align_x(Horizontal::Center)
+        .style(text_input_default)
+        .width(width)
+        .size(14)
+        .into()
+}
+
 fn bag_tab_bar<'a>(
     selected: &Option<Id>,
     images: &HashMap<String, image::Handle>,
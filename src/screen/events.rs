@@ -0,0 +1,317 @@
+//! The **Events** screen.
+//!
+//! Renders a search box above two scrollable lists: the story flags and the
+//! story variables of a Gen III save, labelled with the name table bundled
+//! in the game database for the save's [`pk_edit::GameVersion`]. Only entries
+//! with a known name are listed; the search matches names and hex IDs. When
+//! the save has a roaming legendary, a [`crate::widgets::roamer`] panel sits
+//! between the search box and the lists. A Mystery Event picker at the bottom
+//! installs one of the distribution scripts bundled for the game version.
+//!
+//! Owns its own [`Events`] state, [`Message`] enum and [`update`] function,
+//! which write every change straight back through [`Gen3SaveFile::set_flag`]
+//! and [`Gen3SaveFile::set_var`].
+
+use iced::advanced::widget::Id;
+use iced::alignment::Horizontal;
+use iced::color;
+use iced::widget::image;
+use iced::widget::{button, column, container, pick_list, row, scrollable, text, text_input};
+use iced::{Alignment, Element, Length, Padding};
+use pk_edit::error::SaveDataError;
+
+use std::collections::HashMap;
+
+use crate::message;
+use crate::pick_list_default;
+use crate::{menu_bar, roamer, shadow_box_light, tab_bar_button_primary, text_input_default};
+
+use pk_edit::{Gen3GameData, Gen3SaveFile, MysteryEvent, Roamer};
+
+/// Flag and variable values of the open save, with their names.
+#[derive(Debug, Default)]
+pub struct Events {
+    query: String,
+    flag_names: Vec<(usize, String)>,
+    var_names: Vec<(usize, String)>,
+    flags: Vec<bool>,
+    vars: Vec<u16>,
+    mystery_events: Vec<MysteryEvent>,
+    selected_event: Option<String>,
+}
+
+impl Events {
+    /// Reads the flag and var arrays and the name tables for the save's game version.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SaveDataError`] if the game version or the arrays can't be read.
+    pub fn load(save_file: &Gen3SaveFile) -> Result<Self, SaveDataError> {
+        let version = save_file.get_game_version()?;
+        Ok(Self {
+            query: String::new(),
+            flag_names: Gen3GameData.flag_names(version).unwrap_or_default(),
+            var_names: Gen3GameData.var_names(version).unwrap_or_default(),
+            flags: save_file.flags()?,
+            vars: save_file.vars()?,
+            mystery_events: Gen3GameData.mystery_events(version).unwrap_or_default(),
+            selected_event: None,
+        })
+    }
+
+    /// Re-reads the flag and var arrays after another screen wrote to them,
+    /// keeping the search query and the selected Mystery Event.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`SaveDataError`] if the arrays can't be read.
+    pub fn reload(&mut self, save_file: &Gen3SaveFile) -> Result<(), SaveDataError> {
+        self.flags = save_file.flags()?;
+        self.vars = save_file.vars()?;
+        Ok(())
+    }
+
+    fn matches(&self, label: &str, name: &str) -> bool {
+        let query = self.query.trim().to_lowercase();
+        query.is_empty()
+            || name.to_lowercase().contains(&query)
+            || label.to_lowercase().contains(&query)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    QueryChanged(String),
+    FlagToggled(usize),
+    VarChanged(usize, String),
+    MysteryEventSelected(String),
+    InstallMysteryEvent,
+}
+
+pub fn update(
+    events: &mut Events,
+    save_file: &mut Gen3SaveFile,
+    message: Message,
+) -> Result<(), SaveDataError> {
+    match message {
+        Message::QueryChanged(query) => {
+            events.query = query;
+            Ok(())
+        }
+        Message::FlagToggled(id) => {
+            let flag = events
+                .flags
+                .get_mut(id)
+                .ok_or(SaveDataError::InvalidIndex(id))?;
+            *flag = !*flag;
+            save_file.set_flag(id, *flag)
+        }
+        Message::VarChanged(id, mut value) => {
+            value.retain(|c| c.is_ascii_digit());
+            let var = events
+                .vars
+                .get_mut(id)
+                .ok_or(SaveDataError::InvalidIndex(id))?;
+            *var = if value.is_empty() {
+                0
+            } else if let Ok(number) = value.parse::<u64>() {
+                u16::try_from(number).unwrap_or(u16::MAX)
+            } else {
+                u16::MAX
+            };
+            save_file.set_var(id, *var)
+        }
+        Message::MysteryEventSelected(name) => {
+            events.selected_event = Some(name);
+            Ok(())
+        }
+        Message::InstallMysteryEvent => {
+            let Some(event) = events
+                .mystery_events
+                .iter()
+                .find(|event| Some(&event.name) == events.selected_event.as_ref())
+            else {
+                return Ok(());
+            };
+            save_file.install_mystery_event(event)?;
+            // The script sets its own flags and vars, show them right away.
+            events.flags = save_file.flags()?;
+            events.vars = save_file.vars()?;
+            Ok(())
+        }
+    }
+}
+
+pub fn events<'a>(
+    selected_tab: &Option<Id>,
+    events: &Option<Events>,
+    roamer_state: &Option<Roamer>,
+    images: &HashMap<String, image::Handle>,
+) -> Element<'a, message::Message> {
+    let content: Element<'a, message::Message> = match events {
+        Some(events) => {
+            let search: Element<'a, Message> = container(
+                text_input("Search flags and vars by name or ID...", &events.query)
+                    .on_input(Message::QueryChanged)
+                    .style(text_input_default)
+                    .size(14),
+            )
+            .style(shadow_box_light)
+            .padding(Padding::from([5, 10]))
+            .width(1000)
+            .into();
+            let lists: Element<'a, Message> =
+                row![flag_list(events), var_list(events)].spacing(15).into();
+            let mystery_event = mystery_event_picker(events);
+
+            column![
+                search.map(message::Message::Events),
+                match roamer_state {
+                    Some(roamer_state) => {
+                        roamer(roamer_state, images).map(message::Message::Roamer)
+                    }
+                    None => text("").into(),
+                },
+                mystery_event.map(message::Message::Events),
+                lists.map(message::Message::Events),
+            ]
+            .spacing(15)
+            .into()
+        }
+        None => text("").into(),
+    };
+
+    column![
+        menu_bar::view(selected_tab, images).map(message::Message::MenuBar),
+        row![
+            iced::widget::Space::new().width(Length::Fill),
+            content,
+            iced::widget::Space::new().width(Length::Fill),
+        ],
+    ]
+    .spacing(15)
+    .into()
+}
+
+fn mystery_event_picker<'a>(events: &Events) -> Element<'a, Message> {
+    let names: Vec<String> = events
+        .mystery_events
+        .iter()
+        .map(|event| event.name.clone())
+        .collect();
+    let description = events
+        .mystery_events
+        .iter()
+        .find(|event| Some(&event.name) == events.selected_event.as_ref())
+        .map(|event| event.description.clone())
+        .unwrap_or_default();
+
+    container(
+        row![
+            text("Mystery Event").color(color!(0xffcc00)),
+            pick_list(
+                names,
+                events.selected_event.clone(),
+                Message::MysteryEventSelected
+            )
+            .placeholder("Choose a distribution...")
+            .style(pick_list_default)
+            .width(250),
+            text(description).size(12).width(Length::Fill),
+            button(text("Install"))
+                .on_press_maybe(
+                    events
+                        .selected_event
+                        .is_some()
+                        .then_some(Message::InstallMysteryEvent),
+                )
+                .style(tab_bar_button_primary),
+        ]
+        .padding(Padding::from([0, 10]))
+        .align_y(Alignment::Center)
+        .spacing(15),
+    )
+    .style(shadow_box_light)
+    .width(1000)
+    .height(40.0)
+    .into()
+}
+
+fn flag_list<'a>(events: &Events) -> Element<'a, Message> {
+    let mut column = column![text("Flags").color(color!(0xffcc00))].spacing(10);
+
+    for (id, name) in &events.flag_names {
+        let label = format!("0x{id:03X}");
+        if !events.matches(&label, name) {
+            continue;
+        }
+        let Some(value) = events.flags.get(*id) else {
+            continue;
+        };
+
+        column = column.push(
+            container(
+                row![
+                    text(label).width(60),
+                    text(name.clone()).width(Length::Fill),
+                    button(text(if *value { "Set" } else { "Unset" }).center())
+                        .on_press(Message::FlagToggled(*id))
+                        .width(70)
+                        .style(if *value {
+                            button::primary
+                        } else {
+                            button::secondary
+                        }),
+                ]
+                .padding(Padding::from([0, 10]))
+                .align_y(Alignment::Center)
+                .spacing(15),
+            )
+            .style(shadow_box_light)
+            .width(490)
+            .height(40.0),
+        );
+    }
+
+    scrollable(column).into()
+}
+
+fn var_list<'a>(events: &Events) -> Element<'a, Message> {
+    let mut column = column![text("Variables").color(color!(0xffcc00))].spacing(10);
+
+    for (id, name) in &events.var_names {
+        // Vars are addressed by scripts starting at 0x4000.
+        let label = format!("0x{:04X}", id + 0x4000);
+        if !events.matches(&label, name) {
+            continue;
+        }
+        let Some(value) = events.vars.get(*id) else {
+            continue;
+        };
+        let id = *id;
+        let value = value.to_string();
+
+        column = column.push(
+            container(
+                row![
+                    text(label).width(60),
+                    text(name.clone()).width(Length::Fill),
+                    text_input(&value, &value)
+                        .on_input(move |input| Message::VarChanged(id, input))
+                        .align_x(Horizontal::Center)
+                        .style(text_input_default)
+                        .width(70)
+                        .size(14),
+                ]
+                .padding(Padding::from([0, 10]))
+                .align_y(Alignment::Center)
+                .spacing(15),
+            )
+            .style(shadow_box_light)
+            .width(490)
+            .height(40.0),
+        );
+    }
+
+    scrollable(column).into()
+}
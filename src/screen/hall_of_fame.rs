@@ -0,0 +1,252 @@
+//! The **Hall of Fame** screen.
+//!
+//! Renders a header with bulk actions above a scrollable list of every team
+//! recorded in the Gen III Hall of Fame, oldest first. Each entry shows the
+//! members' sprites with editable nicknames and levels, their OT ID and PID.
+//!
+//! Owns its own [`Message`] enum and [`update`] function which write the whole
+//! [`HallOfFame`] back through [`Gen3SaveFile::save_hall_of_fame`].
+
+use iced::advanced::widget::Id;
+use iced::alignment::Horizontal;
+use iced::widget::image;
+use iced::widget::{button, column, container, row, scrollable, text, text_input};
+use iced::{Alignment, Element, Length, Padding};
+use pk_edit::error::SaveDataError;
+
+use std::collections::HashMap;
+
+use crate::message;
+use crate::{menu_bar, tab_bar_button_primary};
+use crate::{shadow_box_light, text_input_default, valid_nickname};
+
+use pk_edit::{AnyPokemon, Gen3SaveFile, HallOfFame, HallOfFameMon, PokemonTrait};
+
+/// The game keeps at most this many teams and drops the oldest one after that.
+const MAX_TEAMS: usize = 50;
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    NicknameChanged(usize, usize, String),
+    NicknameSubmitted(usize, usize),
+    LevelChanged(usize, usize, String),
+    RemoveTeam(usize),
+    AddFromParty,
+    ClearAll,
+}
+
+pub fn update(
+    hall_of_fame: &mut HallOfFame,
+    save_file: &mut Gen3SaveFile,
+    party: &[AnyPokemon],
+    message: Message,
+) -> Result<(), SaveDataError> {
+    match message {
+        Message::NicknameChanged(team, member, nickname) => {
+            member_mut(hall_of_fame, team, member)?.nickname =
+                valid_nickname(&nickname, save_file.nickname_max_len(), |c| {
+                    save_file.can_encode_char(c)
+                });
+        }
+        Message::NicknameSubmitted(team, member) => {
+            fill_empty_nickname(member_mut(hall_of_fame, team, member)?, save_file);
+        }
+        Message::LevelChanged(team, member, mut level) => {
+            level.retain(|c| c.is_ascii_digit());
+            let level = level.parse::<u8>().unwrap_or(1).clamp(1, 100);
+            member_mut(hall_of_fame, team, member)?.level = level;
+        }
+        Message::RemoveTeam(team) => {
+            if team >= hall_of_fame.teams.len() {
+                return Err(SaveDataError::InvalidIndex(team));
+            }
+            hall_of_fame.teams.remove(team);
+        }
+        Message::AddFromParty => {
+            let team: Vec<HallOfFameMon> = party
+                .iter()
+                .filter(|pokemon| !pokemon.is_empty())
+                .map(HallOfFameMon::from_pokemon)
+                .collect();
+            if team.is_empty() {
+                return Ok(());
+            }
+            if hall_of_fame.teams.len() >= MAX_TEAMS {
+                hall_of_fame.teams.remove(0);
+            }
+            hall_of_fame.teams.push(team);
+        }
+        Message::ClearAll => {
+            hall_of_fame.teams.clear();
+        }
+    }
+    // A nickname may be empty while typing, the save always gets a name.
+    let mut saved = hall_of_fame.clone();
+    for member in saved.teams.iter_mut().flatten() {
+        fill_empty_nickname(member, save_file);
+    }
+    save_file.save_hall_of_fame(&saved)
+}
+
+/// An empty nickname falls back to the species name, like in-game.
+fn fill_empty_nickname(member: &mut HallOfFameMon, save_file: &Gen3SaveFile) {
+    if member.nickname.is_empty() {
+        member.nickname = valid_nickname(
+            &member.species.to_uppercase(),
+            save_file.nickname_max_len(),
+            |c| save_file.can_encode_char(c),
+        );
+    }
+}
+
+fn member_mut(
+    hall_of_fame: &mut HallOfFame,
+    team: usize,
+    member: usize,
+) -> Result<&mut HallOfFameMon, SaveDataError> {
+    hall_of_fame
+        .teams
+        .get_mut(team)
+        .ok_or(SaveDataError::InvalidIndex(team))?
+        .get_mut(member)
+        .ok_or(SaveDataError::InvalidIndex(member))
+}
+
+pub fn hall_of_fame<'a>(
+    selected_tab: &Option<Id>,
+    hall_of_fame: &Option<HallOfFame>,
+    party: &[AnyPokemon],
+    images: &HashMap<String, image::Handle>,
+) -> Element<'a, message::Message> {
+    let content: Element<'a, Message> = match hall_of_fame {
+        Some(hall_of_fame) => column![
+            hall_of_fame_actions(hall_of_fame, party),
+            hall_of_fame_teams(hall_of_fame, images),
+        ]
+        .spacing(15)
+        .into(),
+        None => text("").into(),
+    };
+
+    column![
+        menu_bar::view(selected_tab, images).map(message::Message::MenuBar),
+        row![
+            iced::widget::Space::new().width(Length::Fill),
+            content.map(message::Message::HallOfFame),
+            iced::widget::Space::new().width(Length::Fill),
+        ],
+    ]
+    .spacing(15)
+    .into()
+}
+
+fn hall_of_fame_actions<'a>(
+    hall_of_fame: &HallOfFame,
+    party: &[AnyPokemon],
+) -> Element<'a, Message> {
+    let has_party = party.iter().any(|pokemon| !pokemon.is_empty());
+
+    container(
+        row![
+            text(format!(
+                "{} / {MAX_TEAMS} entries",
+                hall_of_fame.teams.len()
+            )),
+            iced::widget::Space::new().width(Length::Fill),
+            button(text("Add current party"))
+                .on_press_maybe(has_party.then_some(Message::AddFromParty))
+                .style(tab_bar_button_primary),
+            button(text("Clear"))
+                .on_press(Message::ClearAll)
+                .style(tab_bar_button_primary),
+        ]
+        .padding(Padding::from([0, 10]))
+        .align_y(Alignment::Center)
+        .spacing(10),
+    )
+    .style(shadow_box_light)
+    .width(800)
+    .height(40.0)
+    .into()
+}
+
+fn hall_of_fame_teams<'a>(
+    hall_of_fame: &HallOfFame,
+    images: &HashMap<String, image::Handle>,
+) -> Element<'a, Message> {
+    let mut column = column![].spacing(10);
+
+    for (i, team) in hall_of_fame.teams.iter().enumerate() {
+        let mut members = row![].spacing(10);
+        for (j, member) in team.iter().enumerate() {
+            members = members.push(team_member(i, j, member, images));
+        }
+
+        column = column.push(
+            container(
+                column![
+                    row![
+                        text(format!("Entry {}", i + 1)),
+                        iced::widget::Space::new().width(Length::Fill),
+                        button(text("Remove"))
+                            .on_press(Message::RemoveTeam(i))
+                            .style(tab_bar_button_primary),
+                    ]
+                    .align_y(Alignment::Center),
+                    members,
+                ]
+                .spacing(10),
+            )
+            .style(shadow_box_light)
+            .padding(10)
+            .width(800),
+        );
+    }
+
+    scrollable(column).into()
+}
+
+fn team_member<'a>(
+    team: usize,
+    member: usize,
+    pokemon: &HallOfFameMon,
+    images: &HashMap<String, image::Handle>,
+) -> Element<'a, Message> {
+    let sprite = image(
+        images
+            .get(&format!("{:0width$}", pokemon.dex_number, width = 4))
+            .unwrap_or({
+                let width = 10;
+                let height = 10;
+                let size = (width * height) as usize;
+                let pixels = vec![0u8; size * 4];
+                &image::Handle::from_rgba(width, height, pixels)
+            }),
+    )
+    .height(60);
+
+    column![
+        sprite,
+        text_input(&pokemon.species, &pokemon.nickname)
+            .on_input(move |input| Message::NicknameChanged(team, member, input))
+            .on_submit(Message::NicknameSubmitted(team, member))
+            .style(text_input_default)
+            .size(12),
+        row![
+            text("Lv.").size(12),
+            text_input("", &pokemon.level.to_string())
+                .on_input(move |input| Message::LevelChanged(team, member, input))
+                .align_x(Horizontal::Center)
+                .style(text_input_default)
+                .size(12),
+        ]
+        .align_y(Alignment::Center)
+        .spacing(5),
+        text(format!("ID {:05}", pokemon.ot_id.public)).size(12),
+        text(format!("PID {:08X}", pokemon.personality_value)).size(12),
+    ]
+    .align_x(Alignment::Center)
+    .spacing(5)
+    .width(120)
+    .into()
+}
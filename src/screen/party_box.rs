@@ -3,36 +3,69 @@
 //! Renders the main editing view consisting of:
 //! - A [`crate::menu_bar`] at the top for file operations and tab navigation.
 //! - A party panel (left) showing the trainer's current party of up to 6 Pokémon.
-//! - A PC box panel (centre) showing a 6 × 5 grid of slots for the active box.
-//! - A Pokémon info panel (right) that appears when a slot is selected.
+//! - A PC box panel (centre) showing the grid of slots for the active box,
+//!   with the [`crate::widgets::daycare`] parent slots below it when the save
+//!   has a Day Care.
+//! - A Pokémon info panel (right) that appears when a slot is selected, with
+//!   **Import…** / **Export…** actions for `.pk3` / `.ek3` files, a
+//!   **Copy set** action for Showdown text, an **Evolve into…** menu and
+//!   **Make egg** / **Hatch now** below it.
+//! - **Paste team** actions under the party and PC box that fill their empty
+//!   slots from Showdown text on the clipboard, and **Heal party**.
 
 use iced::advanced::widget::Id;
 use iced::widget::container;
 use iced::widget::image;
-use iced::widget::{column, row};
-use iced::Element;
+use iced::widget::{button, column, pick_list, row, text};
+use iced::{Element, Length};
 
 use std::collections::HashMap;
+use std::fmt;
 
 use crate::menu_bar;
+use crate::pick_list_default;
 use crate::pokemon_info;
+use crate::pokemon_info::LegalMode;
+use crate::tab_bar_button_primary;
 use crate::DragState;
 use crate::Message;
-use crate::{widgets::party, widgets::pc_box};
+use crate::{widgets::daycare, widgets::party, widgets::pc_box};
 
-use pk_edit::{AnyGameData, AnyPokemon};
+use pk_edit::legality::LegalityReport;
+use pk_edit::{
+    AnyGameData, AnyPokemon, DayCare, EvolutionMethod, GameData, Location, PokemonTrait,
+};
 
 const SPACING: f32 = 15.0;
 
+/// An entry of the **Evolve into…** menu.
+#[derive(Debug, Clone, PartialEq)]
+struct EvolveChoice {
+    species: String,
+    label: String,
+}
+
+impl fmt::Display for EvolveChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label)
+    }
+}
+
 pub fn party_box<'a>(
     cb_state: &'a iced::widget::combo_box::State<String>,
+    legal_mode: &LegalMode,
+    experience_input: &Option<String>,
     selected: &Option<Id>,
     selected_tab: &Option<Id>,
     selected_pokemon: &Option<AnyPokemon>,
+    legality: Option<&LegalityReport>,
     game_data: &AnyGameData,
     party_list: &'a [AnyPokemon],
     pc_i: &usize,
+    box_size: (usize, usize),
     pc_list: &'a [AnyPokemon],
+    daycare_list: &'a [AnyPokemon],
+    daycare_info: &Option<DayCare>,
     images: &HashMap<String, image::Handle>,
     drag: &Option<DragState>,
     scale: f32,
@@ -45,14 +78,36 @@ pub fn party_box<'a>(
             row![
                 iced::widget::Space::new().width(5.0 * scale),
                 party(selected, party_list, images, drag),
-                pc_box(selected, pc_i, pc_list, images, drag, scale)
+                column![
+                    pc_box(selected, pc_i, box_size, pc_list, images, drag, scale),
+                    if daycare_list.is_empty() {
+                        container("").into()
+                    } else {
+                        daycare(selected, daycare_list, daycare_info, images, drag)
+                    },
+                ]
+                .spacing(SPACING * scale),
             ]
             .spacing(SPACING * scale),
+            paste_actions(*pc_i, scale),
         ]
         .spacing(SPACING * scale),
         if let Some(selected_pokemon) = selected_pokemon {
-            pokemon_info(cb_state, selected_pokemon, game_data, images, scale)
-                .map(Message::PokemonInfo)
+            column![
+                pokemon_info(
+                    cb_state,
+                    legal_mode,
+                    experience_input,
+                    selected_pokemon,
+                    legality,
+                    game_data,
+                    images,
+                    scale
+                )
+                .map(Message::PokemonInfo),
+                slot_actions(selected_pokemon, game_data, info_w),
+            ]
+            .into()
         } else {
             container("").width(info_w).into()
         },
@@ -60,3 +115,112 @@ pub fn party_box<'a>(
     .spacing(SPACING * scale)
     .into()
 }
+
+/// Evolutions and the pre-evolution of `pokemon`, labelled with their method.
+fn evolve_choices(pokemon: &AnyPokemon, game_data: &AnyGameData) -> Vec<EvolveChoice> {
+    if pokemon.is_empty() || pokemon.is_egg() {
+        return Vec::new();
+    }
+    let species = pokemon.species();
+
+    game_data
+        .evolution_chain(pokemon.nat_dex_number())
+        .unwrap_or_default()
+        .into_iter()
+        // Shedinja only appears next to an evolving Nincada, it isn't a target itself.
+        .filter(|evolution| !matches!(evolution.method, EvolutionMethod::Shed(_)))
+        .filter_map(|evolution| {
+            if evolution.from == species {
+                Some(EvolveChoice {
+                    label: format!("{} ({})", evolution.to, evolution.method),
+                    species: evolution.to,
+                })
+            } else if evolution.to == species {
+                Some(EvolveChoice {
+                    label: format!("Devolve into {}", evolution.from),
+                    species: evolution.from,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn slot_actions<'a>(
+    pokemon: &AnyPokemon,
+    game_data: &AnyGameData,
+    width: f32,
+) -> Element<'a, Message> {
+    let egg_action: Element<'a, Message> = if pokemon.is_egg() {
+        button(text("Hatch now").center())
+            .on_press(Message::PokemonInfo(pokemon_info::Message::HatchNow))
+            .width(Length::Fill)
+            .style(tab_bar_button_primary)
+            .into()
+    } else if pokemon.is_empty() {
+        pick_list(
+            game_data.species().unwrap_or_default(),
+            None::<String>,
+            |species| Message::PokemonInfo(pokemon_info::Message::MakeEgg(species)),
+        )
+        .placeholder("Make egg of…")
+        .width(Length::Fill)
+        .style(pick_list_default)
+        .into()
+    } else {
+        // Making an egg would overwrite the Pokémon in this slot.
+        button(text("Make egg").center())
+            .width(Length::Fill)
+            .style(tab_bar_button_primary)
+            .into()
+    };
+
+    let files = row![
+        button(text("Import…").center())
+            .on_press(Message::ImportPokemon)
+            .width(Length::Fill)
+            .style(tab_bar_button_primary),
+        button(text("Export…").center())
+            .on_press_maybe((!pokemon.is_empty()).then_some(Message::ExportPokemon))
+            .width(Length::Fill)
+            .style(tab_bar_button_primary),
+        button(text("Copy set").center())
+            .on_press_maybe((!pokemon.is_empty()).then_some(Message::CopyShowdown))
+            .width(Length::Fill)
+            .style(tab_bar_button_primary),
+    ]
+    .spacing(10);
+
+    let evolve = pick_list(
+        evolve_choices(pokemon, game_data),
+        None::<EvolveChoice>,
+        |choice| Message::Evolve(choice.species),
+    )
+    .placeholder("Evolve into…")
+    .width(Length::Fill)
+    .style(pick_list_default);
+
+    column![files, row![evolve, egg_action].spacing(10)]
+        .spacing(10)
+        .padding([5, 15])
+        .width(width)
+        .into()
+}
+
+fn paste_actions<'a>(pc_i: usize, scale: f32) -> Element<'a, Message> {
+    row![
+        button(text("Paste team into party").center())
+            .on_press(Message::PasteShowdown(Location::Party))
+            .style(tab_bar_button_primary),
+        button(text("Paste team into box").center())
+            .on_press(Message::PasteShowdown(Location::Box(pc_i)))
+            .style(tab_bar_button_primary),
+        button(text("Heal party").center())
+            .on_press(Message::HealParty)
+            .style(tab_bar_button_primary),
+    ]
+    .spacing(10.0 * scale)
+    .padding([0.0, 5.0 * scale])
+    .into()
+}
@@ -0,0 +1,194 @@
+//! The **Pokédex** screen.
+//!
+//! Renders a scrollable list of every National Dex entry the game knows about,
+//! each row showing the sprite, number, species name and **Seen** / **Caught**
+//! toggles, under a header with bulk actions and the National Dex switch.
+//!
+//! Owns its own [`Message`] enum and [`update`] function which keep the two
+//! flags consistent (caught implies seen) and write the whole [`Pokedex`] back
+//! through [`Gen3SaveFile::save_pokedex`], which updates every redundant copy.
+
+use iced::advanced::widget::Id;
+use iced::widget::image;
+use iced::widget::{button, column, container, row, scrollable, text};
+use iced::{Alignment, Element, Length, Padding};
+use pk_edit::error::SaveDataError;
+
+use std::collections::HashMap;
+
+use crate::message;
+use crate::shadow_box_light;
+use crate::{menu_bar, tab_bar_button_primary, toggle_style};
+
+use pk_edit::{AnyGameData, GameData, Gen3SaveFile, Pokedex};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    SeenToggled(usize),
+    CaughtToggled(usize),
+    MarkAllSeen,
+    MarkAllCaught,
+    ClearAll,
+    NationalDexToggled,
+}
+
+pub fn update(
+    pokedex: &mut Pokedex,
+    save_file: &mut Gen3SaveFile,
+    message: Message,
+) -> Result<(), SaveDataError> {
+    match message {
+        Message::SeenToggled(i) => {
+            let seen = pokedex
+                .seen
+                .get_mut(i)
+                .ok_or(SaveDataError::InvalidIndex(i))?;
+            *seen = !*seen;
+            // An entry can't be caught without having been seen.
+            if !*seen {
+                if let Some(caught) = pokedex.caught.get_mut(i) {
+                    *caught = false;
+                }
+            }
+        }
+        Message::CaughtToggled(i) => {
+            let caught = pokedex
+                .caught
+                .get_mut(i)
+                .ok_or(SaveDataError::InvalidIndex(i))?;
+            *caught = !*caught;
+            if *caught {
+                if let Some(seen) = pokedex.seen.get_mut(i) {
+                    *seen = true;
+                }
+            }
+        }
+        Message::MarkAllSeen => {
+            pokedex.seen.iter_mut().for_each(|seen| *seen = true);
+        }
+        Message::MarkAllCaught => {
+            pokedex.seen.iter_mut().for_each(|seen| *seen = true);
+            pokedex.caught.iter_mut().for_each(|caught| *caught = true);
+        }
+        Message::ClearAll => {
+            pokedex.seen.iter_mut().for_each(|seen| *seen = false);
+            pokedex.caught.iter_mut().for_each(|caught| *caught = false);
+        }
+        Message::NationalDexToggled => {
+            pokedex.national_dex = !pokedex.national_dex;
+        }
+    }
+    save_file.save_pokedex(pokedex)
+}
+
+pub fn pokedex<'a>(
+    selected_tab: &Option<Id>,
+    pokedex: &Option<Pokedex>,
+    game_data: &AnyGameData,
+    images: &HashMap<String, image::Handle>,
+) -> Element<'a, message::Message> {
+    let content: Element<'a, message::Message> = match pokedex {
+        Some(pokedex) => column![
+            pokedex_actions(pokedex).map(message::Message::Pokedex),
+            pokedex_entries(pokedex, game_data, images).map(message::Message::Pokedex),
+        ]
+        .spacing(15)
+        .into(),
+        None => text("").into(),
+    };
+
+    column![
+        menu_bar::view(selected_tab, images).map(message::Message::MenuBar),
+        row![
+            iced::widget::Space::new().width(Length::Fill),
+            content,
+            iced::widget::Space::new().width(Length::Fill),
+        ],
+    ]
+    .spacing(15)
+    .into()
+}
+
+fn pokedex_actions<'a>(pokedex: &Pokedex) -> Element<'a, Message> {
+    let seen = pokedex.seen.iter().filter(|seen| **seen).count();
+    let caught = pokedex.caught.iter().filter(|caught| **caught).count();
+
+    container(
+        row![
+            text(format!("Seen {seen} · Caught {caught}")).shaping(text::Shaping::Advanced),
+            iced::widget::Space::new().width(Length::Fill),
+            button(text("Mark all seen"))
+                .on_press(Message::MarkAllSeen)
+                .style(tab_bar_button_primary),
+            button(text("Mark all caught"))
+                .on_press(Message::MarkAllCaught)
+                .style(tab_bar_button_primary),
+            button(text("Clear"))
+                .on_press(Message::ClearAll)
+                .style(tab_bar_button_primary),
+            button(text("National Dex"))
+                .on_press(Message::NationalDexToggled)
+                .style(toggle_style(pokedex.national_dex)),
+        ]
+        .padding(Padding::from([0, 10]))
+        .align_y(Alignment::Center)
+        .spacing(10),
+    )
+    .style(shadow_box_light)
+    .width(600)
+    .height(40.0)
+    .into()
+}
+
+fn pokedex_entries<'a>(
+    pokedex: &Pokedex,
+    game_data: &AnyGameData,
+    images: &HashMap<String, image::Handle>,
+) -> Element<'a, Message> {
+    let mut column = column![].spacing(10);
+
+    for (i, (seen, caught)) in pokedex.seen.iter().zip(&pokedex.caught).enumerate() {
+        let dex_number = i + 1;
+        let name = u16::try_from(dex_number)
+            .ok()
+            .and_then(|dex_number| game_data.species_name(dex_number).ok())
+            .unwrap_or_else(|| format!("No. {dex_number}"));
+
+        let sprite = image(
+            images
+                .get(&format!("{:0width$}", dex_number, width = 4))
+                .unwrap_or({
+                    let width = 10;
+                    let height = 10;
+                    let size = (width * height) as usize;
+                    let pixels = vec![0u8; size * 4];
+                    &image::Handle::from_rgba(width, height, pixels)
+                }),
+        )
+        .height(40);
+
+        column = column.push(
+            container(
+                row![
+                    sprite,
+                    text(format!("No. {:03}", dex_number)).width(70),
+                    text(name).width(Length::Fill),
+                    button(text("Seen"))
+                        .on_press(Message::SeenToggled(i))
+                        .style(toggle_style(*seen)),
+                    button(text("Caught"))
+                        .on_press(Message::CaughtToggled(i))
+                        .style(toggle_style(*caught)),
+                ]
+                .padding(Padding::from([0, 10]))
+                .align_y(Alignment::Center)
+                .spacing(25),
+            )
+            .style(shadow_box_light)
+            .width(600)
+            .height(40.0),
+        );
+    }
+
+    scrollable(column).into()
+}
@@ -0,0 +1,782 @@
+//! Pokémon Showdown set import and export.
+//!
+//! Parses the plain-text set format used by the Showdown teambuilder:
+//!
+//! ```text
+//! Nickname (Species) (M) @ Item
+//! Ability: Levitate
+//! Level: 50
+//! Shiny: Yes
+//! EVs: 252 SpA / 4 SpD / 252 Spe
+//! Modest Nature
+//! IVs: 0 Atk
+//! - Psychic
+//! - Hidden Power [Fire]
+//! ```
+//!
+//! [`parse_team`] turns a whole team (sets separated by blank lines) into
+//! [`ShowdownSet`]s, [`build`] validates a set against the active
+//! [`AnyGameData`] and creates the Pokémon through
+//! [`PokemonFactoryTrait::gen_pokemon_from_species`], and [`to_showdown`]
+//! serializes an existing Pokémon back to text.
+//!
+//! Showdown spells a few names differently from the games: `Nidoran-F` /
+//! `Nidoran-M`, `Farfetch’d` with a curly apostrophe and forms as a species
+//! suffix (`Unown-B`, `Deoxys-Attack`). Names are compared with both spellings
+//! folded together, and a Hidden Power type sets the low bits of the IVs to match it.
+
+use std::fmt;
+
+use pk_edit::error::PokemonError;
+use pk_edit::{
+    AnyFactory, AnyGameData, AnyPokemon, GameData, Gender, PokemonFactoryTrait, PokemonTrait,
+    TrainerID, NATURE,
+};
+
+use crate::error::Error;
+
+/// Showdown stat abbreviations paired with the stat names used by [`PokemonTrait::update_iv`].
+const STATS: [(&str, &str); 6] = [
+    ("HP", "HP"),
+    ("Atk", "Attack"),
+    ("Def", "Defense"),
+    ("SpA", "Sp. Atk"),
+    ("SpD", "Sp. Def"),
+    ("Spe", "Speed"),
+];
+
+/// Keys Showdown emits for later generations that have no Gen III equivalent.
+const IGNORED_KEYS: [&str; 4] = ["Tera Type", "Dynamax Level", "Gigantamax", "Pokeball"];
+
+const HIDDEN_POWER: &str = "Hidden Power";
+
+/// Hidden Power types in the order of the value computed from the IVs.
+const HIDDEN_POWER_TYPES: [&str; 16] = [
+    "Fighting", "Flying", "Poison", "Ground", "Rock", "Bug", "Ghost", "Steel", "Fire", "Water",
+    "Grass", "Electric", "Psychic", "Ice", "Dragon", "Dark",
+];
+
+/// Weight of each IV's low bit in the Hidden Power type, in [`STATS`] order.
+/// Speed comes before the special stats in the formula.
+const HIDDEN_POWER_WEIGHTS: [u16; 6] = [1, 2, 4, 16, 32, 8];
+
+/// Showdown form suffixes for forms whose in-game name is a symbol.
+const FORM_ALIASES: [(&str, &str); 2] = [("!", "Exclamation"), ("?", "Question")];
+
+const MAX_EV: u16 = 255;
+const MAX_IV: u16 = 31;
+const MAX_MOVES: usize = 4;
+
+/// A single parsed Showdown set.
+///
+/// Names are stored as written; they are matched against the active game's
+/// data only when the set is [`build`]t. `Display` writes the set back as
+/// Showdown text.
+#[derive(Debug, Clone, Default)]
+pub struct ShowdownSet {
+    pub species: String,
+    pub nickname: Option<String>,
+    pub gender: Option<Gender>,
+    pub item: Option<String>,
+    pub ability: Option<String>,
+    pub level: Option<u8>,
+    pub shiny: bool,
+    pub friendship: Option<u8>,
+    pub nature: Option<String>,
+    /// `(stat name, value)` pairs, using the stat names of [`PokemonTrait::update_ev`].
+    pub evs: Vec<(String, u16)>,
+    /// `(stat name, value)` pairs, using the stat names of [`PokemonTrait::update_iv`].
+    pub ivs: Vec<(String, u16)>,
+    /// The type written as `Hidden Power [Type]`, one of [`HIDDEN_POWER_TYPES`].
+    pub hidden_power: Option<String>,
+    pub moves: Vec<String>,
+}
+
+/// Parses a whole team, one set per blank-line separated block.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidShowdownLine`] with the 1-based line number of the
+/// first line that can't be parsed.
+pub fn parse_team(input: &str) -> Result<Vec<ShowdownSet>, Error> {
+    let mut sets = vec![];
+    let mut block: Vec<(usize, &str)> = vec![];
+
+    for (number, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            if !block.is_empty() {
+                sets.push(parse_set(&block)?);
+                block.clear();
+            }
+        } else {
+            block.push((number + 1, line));
+        }
+    }
+    if !block.is_empty() {
+        sets.push(parse_set(&block)?);
+    }
+
+    Ok(sets)
+}
+
+fn parse_set(lines: &[(usize, &str)]) -> Result<ShowdownSet, Error> {
+    let Some(((first_number, first), rest)) = lines.split_first() else {
+        return Ok(ShowdownSet::default());
+    };
+
+    let mut set = parse_header(*first_number, first)?;
+
+    for (number, line) in rest {
+        let invalid = || Error::InvalidShowdownLine(*number, line.to_string());
+
+        if let Some(name) = line.strip_prefix('-') {
+            if set.moves.len() == MAX_MOVES {
+                return Err(invalid());
+            }
+            let name = move_name(name);
+            if name.eq_ignore_ascii_case(HIDDEN_POWER) {
+                if let Some(kind) = hidden_power_type(line) {
+                    set.hidden_power = Some(hidden_power(kind).ok_or_else(invalid)?);
+                }
+            }
+            set.moves.push(name);
+        } else if let Some(nature) = line.strip_suffix(" Nature") {
+            set.nature = Some(nature.trim().to_string());
+        } else if let Some((key, value)) = line.split_once(':') {
+            let value = value.trim();
+            match key.trim() {
+                "Ability" => set.ability = Some(value.to_string()),
+                "Level" => {
+                    let level = value.parse::<u8>().map_err(|_| invalid())?;
+                    if !(1..=100).contains(&level) {
+                        return Err(invalid());
+                    }
+                    set.level = Some(level);
+                }
+                "Shiny" => {
+                    set.shiny = match value {
+                        v if v.eq_ignore_ascii_case("yes") => true,
+                        v if v.eq_ignore_ascii_case("no") => false,
+                        _ => return Err(invalid()),
+                    }
+                }
+                "Happiness" => set.friendship = Some(value.parse::<u8>().map_err(|_| invalid())?),
+                "EVs" => set.evs = parse_spread(value, MAX_EV).ok_or_else(invalid)?,
+                "IVs" => set.ivs = parse_spread(value, MAX_IV).ok_or_else(invalid)?,
+                "Hidden Power" => set.hidden_power = Some(hidden_power(value).ok_or_else(invalid)?),
+                key if IGNORED_KEYS.contains(&key) => {}
+                _ => return Err(invalid()),
+            }
+        } else {
+            return Err(invalid());
+        }
+    }
+
+    Ok(set)
+}
+
+/// Parses `Nickname (Species) (M) @ Item`, where every part but the species is optional.
+fn parse_header(number: usize, line: &str) -> Result<ShowdownSet, Error> {
+    let mut set = ShowdownSet::default();
+
+    let (mut name, item) = match line.split_once(" @ ") {
+        Some((name, item)) => (name.trim(), Some(item.trim())),
+        None => (line.trim(), None),
+    };
+    set.item = item.map(str::to_string);
+
+    if let Some(rest) = name.strip_suffix(" (M)") {
+        set.gender = Some(Gender::M);
+        name = rest;
+    } else if let Some(rest) = name.strip_suffix(" (F)") {
+        set.gender = Some(Gender::F);
+        name = rest;
+    }
+
+    match name
+        .strip_suffix(')')
+        .and_then(|name| name.rsplit_once(" ("))
+    {
+        Some((nickname, species)) => {
+            set.nickname = Some(nickname.trim().to_string());
+            set.species = species.trim().to_string();
+        }
+        None => set.species = name.to_string(),
+    }
+
+    if set.species.is_empty() {
+        return Err(Error::InvalidShowdownLine(number, line.to_string()));
+    }
+
+    Ok(set)
+}
+
+/// Parses `252 SpA / 4 SpD / 252 Spe` into `(stat name, value)` pairs.
+fn parse_spread(value: &str, max: u16) -> Option<Vec<(String, u16)>> {
+    value
+        .split('/')
+        .map(|part| {
+            let (amount, stat) = part.trim().split_once(' ')?;
+            let amount = amount.parse::<u16>().ok().filter(|amount| *amount <= max)?;
+            let (_, stat) = STATS
+                .iter()
+                .find(|(short, _)| short.eq_ignore_ascii_case(stat.trim()))?;
+            Some((stat.to_string(), amount))
+        })
+        .collect()
+}
+
+/// Strips alternatives (`Surf / Hydro Pump`) and Hidden Power types (`Hidden Power [Fire]`).
+fn move_name(name: &str) -> String {
+    let name = name.split('/').next().unwrap_or_default();
+    let name = name.split('[').next().unwrap_or_default();
+    name.trim().to_string()
+}
+
+/// The bracketed type of a `- Hidden Power [Fire]` move line.
+fn hidden_power_type(line: &str) -> Option<&str> {
+    let (_, kind) = line.split('/').next()?.split_once('[')?;
+    kind.split_once(']').map(|(kind, _)| kind.trim())
+}
+
+/// The canonical spelling of a Hidden Power type, `None` for Normal and unknown types.
+fn hidden_power(kind: &str) -> Option<String> {
+    HIDDEN_POWER_TYPES
+        .iter()
+        .find(|t| t.eq_ignore_ascii_case(kind))
+        .map(|t| t.to_string())
+}
+
+/// The Hidden Power type of a spread of IVs in [`STATS`] order.
+fn hidden_power_of(ivs: &[u16; 6]) -> Option<&'static str> {
+    let bits: u16 = ivs
+        .iter()
+        .zip(HIDDEN_POWER_WEIGHTS)
+        .map(|(iv, weight)| (iv & 1) * weight)
+        .sum();
+    HIDDEN_POWER_TYPES.get(usize::from(bits * 15 / 63)).copied()
+}
+
+/// Changes the fewest IVs by one point so that they give the Hidden Power type `kind`.
+///
+/// IVs that already have the right low bit are kept, others go down by one
+/// (up from 0), the way Showdown spreads like `30 Atk / 30 SpA` do it.
+fn with_hidden_power(ivs: [u16; 6], kind: &str) -> [u16; 6] {
+    (0..64_u16)
+        .map(|pattern| {
+            let mut adjusted = ivs;
+            for (index, iv) in adjusted.iter_mut().enumerate() {
+                let bit = (pattern >> index) & 1;
+                if *iv & 1 != bit {
+                    *iv = if *iv == 0 { 1 } else { *iv - 1 };
+                }
+            }
+            adjusted
+        })
+        .filter(|adjusted| hidden_power_of(adjusted) == Some(kind))
+        .min_by_key(|adjusted| ivs.iter().zip(adjusted).filter(|(a, b)| a != b).count())
+        .unwrap_or(ivs)
+}
+
+/// A name folded so that Showdown's and the games' spellings compare equal:
+/// case-insensitive, `’` as `'`, `♀` / `♂` as `-F` / `-M` and spaces ignored.
+fn name_key(name: &str) -> String {
+    name.chars()
+        .flat_map(|c| match c {
+            '’' => vec!['\''],
+            '♀' => vec!['-', 'f'],
+            '♂' => vec!['-', 'm'],
+            ' ' => vec![],
+            c => c.to_lowercase().collect(),
+        })
+        .collect()
+}
+
+/// Finds `name` in `names` ignoring Showdown spelling and returns the game's own spelling.
+fn lookup(names: &[String], name: &str) -> Option<String> {
+    let key = name_key(name);
+    names.iter().find(|n| name_key(n) == key).cloned()
+}
+
+/// Splits `Unown-B` into the game's species name and the form suffix.
+///
+/// Names that are species on their own (`Ho-Oh`, `Nidoran-F`) win over a form
+/// suffix. Returns `None` when neither the name nor its base is a species.
+fn lookup_species<'a>(species: &[String], name: &'a str) -> Option<(String, Option<&'a str>)> {
+    if let Some(species) = lookup(species, name) {
+        return Some((species, None));
+    }
+
+    let (base, suffix) = name.split_once('-')?;
+    Some((lookup(species, base)?, Some(suffix)))
+}
+
+/// Finds the form a Showdown suffix names among a species' forms.
+fn lookup_form(forms: Vec<String>, suffix: &str) -> Option<String> {
+    forms.into_iter().find(|form| {
+        let alias = FORM_ALIASES
+            .iter()
+            .find(|(symbol, _)| symbol == form)
+            .map_or(form.as_str(), |(_, alias)| *alias);
+        name_key(alias) == name_key(suffix)
+    })
+}
+
+/// Showdown's spelling of a species and form, e.g. `Nidoran-F` or `Unown-B`.
+/// The first form of a species is its base form and gets no suffix.
+fn showdown_species(pokemon: &AnyPokemon, game_data: &AnyGameData) -> String {
+    let mut species = pokemon
+        .species()
+        .replace('♀', "-F")
+        .replace('♂', "-M")
+        .replace('\'', "’");
+
+    let form = pokemon.form();
+    let forms = game_data
+        .forms(pokemon.nat_dex_number())
+        .unwrap_or_default();
+    if !form.is_empty() && forms.first().is_some_and(|first| *first != form) {
+        let form = FORM_ALIASES
+            .iter()
+            .find(|(symbol, _)| *symbol == form)
+            .map_or(form.as_str(), |(_, alias)| *alias);
+        species.push('-');
+        species.push_str(&form.replace(' ', "-"));
+    }
+
+    species
+}
+
+fn pokemon_error(error: PokemonError) -> Error {
+    Error::Pokemon(error.to_string())
+}
+
+/// Creates a Pokémon in `slot` from a parsed set.
+///
+/// Every name is checked against `game_data` before anything is written, so a
+/// set that refers to a species, item, nature or move the game doesn't know
+/// fails without touching the slot.
+///
+/// # Errors
+///
+/// Returns [`Error::UnknownSpecies`] (also for unknown forms), [`Error::UnknownItem`],
+/// [`Error::UnknownNature`], [`Error::UnknownMove`] or
+/// [`Error::InvalidNickname`] for names the game can't represent, and
+/// [`Error::Pokemon`] if `pk_edit` rejects a value.
+pub fn build(
+    set: &ShowdownSet,
+    slot: &AnyPokemon,
+    factory: &AnyFactory,
+    game_data: &AnyGameData,
+    ot_name: &str,
+    ot_id: TrainerID,
+) -> Result<AnyPokemon, Error> {
+    let (species, form) = lookup_species(&game_data.species().unwrap_or_default(), &set.species)
+        .ok_or_else(|| Error::UnknownSpecies(set.species.clone()))?;
+
+    let item = match &set.item {
+        Some(item) => Some(
+            lookup(&game_data.held_items().unwrap_or_default(), item)
+                .ok_or_else(|| Error::UnknownItem(item.clone()))?,
+        ),
+        None => None,
+    };
+
+    let nature = match &set.nature {
+        Some(nature) => Some(
+            NATURE
+                .iter()
+                .find(|n| n.eq_ignore_ascii_case(nature))
+                .map(|n| n.to_string())
+                .ok_or_else(|| Error::UnknownNature(nature.clone()))?,
+        ),
+        None => None,
+    };
+
+    let all_moves = game_data.moves().unwrap_or_default();
+    let moves = set
+        .moves
+        .iter()
+        .map(|name| lookup(&all_moves, name).ok_or_else(|| Error::UnknownMove(name.clone())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut pokemon = factory
+        .gen_pokemon_from_species(slot, &species, ot_name, ot_id)
+        .map_err(pokemon_error)?;
+
+    if let Some(nickname) = &set.nickname {
+        if nickname.chars().count() > pokemon.nickname_max_len()
+            || !nickname.chars().all(|c| pokemon.can_encode_char(c))
+        {
+            return Err(Error::InvalidNickname(nickname.clone()));
+        }
+        pokemon.set_nickname(nickname).map_err(pokemon_error)?;
+    }
+
+    if let Some(level) = set.level {
+        let level = level.max(game_data.lowest_level(pokemon.nat_dex_number()));
+        pokemon.set_level(level).map_err(pokemon_error)?;
+    }
+
+    // Gender and ability first: `set_nature` rerolls the PID while keeping both.
+    if let Some(gender) = set.gender {
+        pokemon.set_gender(gender).map_err(pokemon_error)?;
+    }
+    if let Some(ability) = &set.ability {
+        pokemon.set_ability(ability).map_err(pokemon_error)?;
+    }
+    if let Some(nature) = &nature {
+        pokemon.set_nature(nature).map_err(pokemon_error)?;
+    }
+    // `set_shiny` searches a PID that keeps the nature, gender and ability above.
+    if set.shiny {
+        pokemon.set_shiny(true).map_err(pokemon_error)?;
+    }
+    // `set_form` rerolls Unown's PID keeping everything above.
+    if let Some(suffix) = form {
+        let forms = game_data
+            .forms(pokemon.nat_dex_number())
+            .unwrap_or_default();
+        let form =
+            lookup_form(forms, suffix).ok_or_else(|| Error::UnknownSpecies(set.species.clone()))?;
+        pokemon.set_form(&form).map_err(pokemon_error)?;
+    }
+
+    // Unlisted IVs default to 31 in Showdown.
+    let mut ivs = STATS.map(|(_, stat)| {
+        set.ivs
+            .iter()
+            .find(|(name, _)| name == stat)
+            .map_or(MAX_IV, |(_, value)| *value)
+    });
+    if let Some(kind) = &set.hidden_power {
+        ivs = with_hidden_power(ivs, kind);
+    }
+    for ((_, stat), iv) in STATS.iter().zip(ivs) {
+        pokemon.update_iv(stat, iv);
+    }
+    for (stat, ev) in &set.evs {
+        pokemon.update_ev(stat, *ev);
+    }
+
+    for (index, name) in moves.iter().enumerate() {
+        pokemon.set_move(index, name).map_err(pokemon_error)?;
+    }
+
+    if let Some(item) = &item {
+        pokemon.set_held_item(item).map_err(pokemon_error)?;
+    }
+    if let Some(friendship) = set.friendship {
+        pokemon.set_friendship(friendship).map_err(pokemon_error)?;
+    }
+
+    pokemon.update_checksum();
+    Ok(pokemon)
+}
+
+/// Serializes a Pokémon as a Showdown set.
+pub fn to_showdown(pokemon: &AnyPokemon, game_data: &AnyGameData) -> String {
+    ShowdownSet::from_pokemon(pokemon, game_data).to_string()
+}
+
+impl ShowdownSet {
+    /// The set of an existing Pokémon, leaving out values Showdown treats as
+    /// defaults (level 100, 255 happiness, 0 EVs and 31 IVs).
+    pub fn from_pokemon(pokemon: &AnyPokemon, game_data: &AnyGameData) -> Self {
+        let species = showdown_species(pokemon, game_data);
+        let nickname = pokemon.nickname();
+        let nickname = (!nickname.is_empty() && !nickname.eq_ignore_ascii_case(&pokemon.species()))
+            .then_some(nickname);
+
+        let evs = pokemon.evs();
+        let evs = [
+            evs.hp,
+            evs.attack,
+            evs.defense,
+            evs.special_attack,
+            evs.special_defense,
+            evs.speed,
+        ];
+        let ivs = pokemon.ivs();
+        let ivs = [
+            ivs.hp,
+            ivs.attack,
+            ivs.defense,
+            ivs.special_attack,
+            ivs.special_defense,
+            ivs.speed,
+        ]
+        .map(u16::from);
+
+        let moves: Vec<String> = pokemon.moves().into_iter().map(|m| m.name).collect();
+        let hidden_power = moves
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(HIDDEN_POWER))
+            .then(|| hidden_power_of(&ivs).map(str::to_string))
+            .flatten();
+
+        Self {
+            species,
+            nickname,
+            gender: match pokemon.gender() {
+                Gender::M => Some(Gender::M),
+                Gender::F => Some(Gender::F),
+                Gender::None => None,
+            },
+            item: pokemon.held_item().filter(|item| item != "Nothing"),
+            ability: Some(pokemon.ability()),
+            level: Some(pokemon.level()).filter(|level| *level != 100),
+            shiny: pokemon.is_shiny(),
+            friendship: Some(pokemon.friendship()).filter(|friendship| *friendship != u8::MAX),
+            nature: Some(pokemon.nature()),
+            evs: STATS
+                .iter()
+                .zip(evs)
+                .filter(|(_, value)| *value != 0)
+                .map(|((_, stat), value)| (stat.to_string(), u16::from(value)))
+                .collect(),
+            ivs: STATS
+                .iter()
+                .zip(ivs)
+                .filter(|(_, value)| *value != MAX_IV)
+                .map(|((_, stat), value)| (stat.to_string(), value))
+                .collect(),
+            hidden_power,
+            moves,
+        }
+    }
+}
+
+/// Formats `(stat name, value)` pairs as `252 SpA / 4 SpD`.
+fn format_spread(spread: &[(String, u16)]) -> String {
+    spread
+        .iter()
+        .filter_map(|(stat, value)| {
+            let (short, _) = STATS.iter().find(|(_, name)| name == stat)?;
+            Some(format!("{value} {short}"))
+        })
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+impl fmt::Display for ShowdownSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.nickname {
+            Some(nickname) => write!(f, "{nickname} ({})", self.species)?,
+            None => write!(f, "{}", self.species)?,
+        }
+        match self.gender {
+            Some(Gender::M) => write!(f, " (M)")?,
+            Some(Gender::F) => write!(f, " (F)")?,
+            Some(Gender::None) | None => {}
+        }
+        if let Some(item) = &self.item {
+            write!(f, " @ {item}")?;
+        }
+
+        if let Some(ability) = &self.ability {
+            write!(f, "\nAbility: {ability}")?;
+        }
+        if let Some(level) = self.level {
+            write!(f, "\nLevel: {level}")?;
+        }
+        if self.shiny {
+            write!(f, "\nShiny: Yes")?;
+        }
+        if let Some(friendship) = self.friendship {
+            write!(f, "\nHappiness: {friendship}")?;
+        }
+        if !self.evs.is_empty() {
+            write!(f, "\nEVs: {}", format_spread(&self.evs))?;
+        }
+        if let Some(nature) = &self.nature {
+            write!(f, "\n{nature} Nature")?;
+        }
+        if !self.ivs.is_empty() {
+            write!(f, "\nIVs: {}", format_spread(&self.ivs))?;
+        }
+
+        for name in &self.moves {
+            match &self.hidden_power {
+                Some(kind) if name.eq_ignore_ascii_case(HIDDEN_POWER) => {
+                    write!(f, "\n- {name} [{kind}]")?;
+                }
+                Some(_) | None => write!(f, "\n- {name}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LATIAS: &str = "Latias (F) @ Soul Dew
+Ability: Levitate
+Level: 50
+Shiny: Yes
+EVs: 252 SpA / 4 SpD / 252 Spe
+Modest Nature
+IVs: 0 Atk
+- Psychic
+- Dragon Claw
+- Hidden Power [Fire]";
+
+    fn parse_one(input: &str) -> Result<ShowdownSet, Error> {
+        parse_team(input)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::InvalidShowdownLine(1, input.to_string()))
+    }
+
+    fn strings(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[test]
+    fn set_round_trips() -> Result<(), Error> {
+        let set = parse_one(LATIAS)?;
+
+        assert_eq!(set.species, "Latias");
+        assert!(matches!(set.gender, Some(Gender::F)));
+        assert_eq!(set.item.as_deref(), Some("Soul Dew"));
+        assert_eq!(set.level, Some(50));
+        assert!(set.shiny);
+        assert_eq!(set.nature.as_deref(), Some("Modest"));
+        assert_eq!(set.ivs, vec![(String::from("Attack"), 0)]);
+        assert_eq!(set.hidden_power.as_deref(), Some("Fire"));
+        assert_eq!(
+            set.moves,
+            strings(&["Psychic", "Dragon Claw", "Hidden Power"])
+        );
+
+        assert_eq!(set.to_string(), LATIAS);
+        assert_eq!(parse_one(&set.to_string())?.to_string(), LATIAS);
+        Ok(())
+    }
+
+    #[test]
+    fn team_splits_on_blank_lines() -> Result<(), Error> {
+        let team = format!("\n{LATIAS}\n\n\nSparky (Pikachu) (M) @ Light Ball\n- Thunderbolt\n");
+        let sets = parse_team(&team)?;
+
+        assert_eq!(sets.len(), 2);
+        let sparky = sets.last().map(ToString::to_string);
+        assert_eq!(
+            sparky.as_deref(),
+            Some("Sparky (Pikachu) (M) @ Light Ball\n- Thunderbolt")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_lines_report_their_number() {
+        let result = parse_team("Pikachu\nAbility: Static\nLevel: 101");
+        assert!(matches!(result, Err(Error::InvalidShowdownLine(3, _))));
+
+        let result = parse_team("Pikachu\nShiny: Maybe");
+        assert!(matches!(result, Err(Error::InvalidShowdownLine(2, _))));
+
+        let result = parse_team("Pikachu\n- Hidden Power [Normal]");
+        assert!(matches!(result, Err(Error::InvalidShowdownLine(2, _))));
+    }
+
+    #[test]
+    fn header_parts_are_optional() -> Result<(), Error> {
+        let set = parse_header(1, "Mr. Mime")?;
+        assert_eq!(set.species, "Mr. Mime");
+        assert_eq!(set.nickname, None);
+        assert!(set.gender.is_none());
+
+        let set = parse_header(1, "Sparky (Pikachu) @ Light Ball")?;
+        assert_eq!(set.nickname.as_deref(), Some("Sparky"));
+        assert_eq!(set.species, "Pikachu");
+        assert_eq!(set.item.as_deref(), Some("Light Ball"));
+
+        let set = parse_header(1, "Nidoran-F (F)")?;
+        assert_eq!(set.species, "Nidoran-F");
+        assert!(matches!(set.gender, Some(Gender::F)));
+
+        assert!(parse_header(1, " @ Leftovers").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn spreads_are_checked_against_their_limit() {
+        assert_eq!(
+            parse_spread("252 SpA / 4 spd / 252 Spe", MAX_EV),
+            Some(vec![
+                (String::from("Sp. Atk"), 252),
+                (String::from("Sp. Def"), 4),
+                (String::from("Speed"), 252),
+            ])
+        );
+        assert_eq!(parse_spread("32 HP", MAX_IV), None);
+        assert_eq!(parse_spread("4 Luck", MAX_EV), None);
+        assert_eq!(parse_spread("HP", MAX_EV), None);
+    }
+
+    #[test]
+    fn move_names_drop_alternatives_and_types() {
+        assert_eq!(move_name(" Surf / Hydro Pump"), "Surf");
+        assert_eq!(move_name(" Hidden Power [Ice]"), "Hidden Power");
+        assert_eq!(
+            hidden_power_type("- Hidden Power [Ice] / Surf"),
+            Some("Ice")
+        );
+        assert_eq!(hidden_power_type("- Hidden Power"), None);
+        assert_eq!(hidden_power("fire").as_deref(), Some("Fire"));
+        assert_eq!(hidden_power("Normal"), None);
+    }
+
+    #[test]
+    fn hidden_power_sets_the_fewest_ivs() {
+        assert_eq!(hidden_power_of(&[31; 6]), Some("Dark"));
+        assert_eq!(with_hidden_power([31; 6], "Dark"), [31; 6]);
+
+        let fire = with_hidden_power([31; 6], "Fire");
+        assert_eq!(hidden_power_of(&fire), Some("Fire"));
+        assert_eq!(fire.iter().filter(|iv| **iv == 30).count(), 3);
+
+        for kind in HIDDEN_POWER_TYPES {
+            let ivs = with_hidden_power([0, 31, 31, 31, 31, 31], kind);
+            assert_eq!(hidden_power_of(&ivs), Some(kind));
+        }
+    }
+
+    #[test]
+    fn names_match_showdown_spelling() {
+        let species = strings(&["Nidoran♀", "Nidoran♂", "Farfetch'd", "Ho-Oh", "Unown"]);
+
+        assert_eq!(
+            lookup_species(&species, "Nidoran-F"),
+            Some((String::from("Nidoran♀"), None))
+        );
+        assert_eq!(
+            lookup_species(&species, "nidoran-m"),
+            Some((String::from("Nidoran♂"), None))
+        );
+        assert_eq!(
+            lookup_species(&species, "Farfetch’d"),
+            Some((String::from("Farfetch'd"), None))
+        );
+        assert_eq!(
+            lookup_species(&species, "Ho-Oh"),
+            Some((String::from("Ho-Oh"), None))
+        );
+        assert_eq!(
+            lookup_species(&species, "Unown-B"),
+            Some((String::from("Unown"), Some("B")))
+        );
+        assert_eq!(lookup_species(&species, "Deoxys-Attack"), None);
+
+        let unown = strings(&["A", "B", "!", "?"]);
+        assert_eq!(lookup_form(unown.clone(), "b").as_deref(), Some("B"));
+        assert_eq!(lookup_form(unown, "Exclamation").as_deref(), Some("!"));
+        let deoxys = strings(&["Normal", "Attack", "Defense", "Speed"]);
+        assert_eq!(lookup_form(deoxys, "Attack").as_deref(), Some("Attack"));
+    }
+}
@@ -131,6 +131,17 @@ pub fn tab_bar_button_primary(
     }
 }
 
+/// Picks the button style for an on/off toggle.
+pub fn toggle_style(
+    active: bool,
+) -> fn(&iced::Theme, iced::widget::button::Status) -> iced::widget::button::Style {
+    if active {
+        iced::widget::button::primary
+    } else {
+        iced::widget::button::secondary
+    }
+}
+
 pub fn pick_list_default(
     theme: &iced::Theme,
     status: iced::widget::pick_list::Status,
@@ -269,6 +280,46 @@ pub fn input_appearance(
     }
 }
 
+pub fn legal_badge_appearance(
+    theme: &iced::Theme,
+    status: iced::widget::button::Status,
+) -> iced::widget::button::Style {
+    iced::widget::button::Style {
+        background: Some(iced::Background::Color(color!(0x4caf50))),
+        border: Border {
+            radius: 20.0.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        ..iced::widget::button::primary(theme, status)
+    }
+}
+
+pub fn illegal_badge_appearance(
+    theme: &iced::Theme,
+    status: iced::widget::button::Status,
+) -> iced::widget::button::Style {
+    iced::widget::button::Style {
+        background: Some(iced::Background::Color(color!(0xd65c63))),
+        border: Border {
+            radius: 20.0.into(),
+            width: 0.0,
+            color: Color::TRANSPARENT,
+        },
+        ..iced::widget::button::primary(theme, status)
+    }
+}
+
+pub fn nickname_input_appearance(
+    theme: &iced::Theme,
+    status: iced::widget::text_input::Status,
+) -> iced::widget::text_input::Style {
+    iced::widget::text_input::Style {
+        value: Color::BLACK,
+        ..input_appearance(theme, status)
+    }
+}
+
 pub fn pokeball_picker_apperance(
     theme: &iced::Theme,
     status: iced::widget::button::Status,
@@ -0,0 +1,165 @@
+//! Composite widget rendering the Day Care.
+//!
+//! Displays a header with an **Egg ready** toggle above the two parent slots,
+//! each a [`crate::widgets::party_slot`] with its gained-experience counter
+//! underneath. Parent slots take part in selection and drag and drop exactly
+//! like party and PC slots, addressed as [`Location::DayCare`].
+//!
+//! Owns its own [`Message`] enum and [`update`] function for the counters and
+//! the egg flag, which are written back through [`Gen3SaveFile::save_daycare`].
+
+use iced::advanced::image;
+use iced::advanced::widget::Id;
+use iced::alignment::Horizontal;
+use iced::widget::{button, column, container, row, text, text_input};
+use iced::{Alignment, Element, Length, Padding};
+use pk_edit::error::SaveDataError;
+
+use std::collections::HashMap;
+
+use crate::widgets::{party_slot, sprite_key};
+use crate::DragState;
+use crate::{message, shadow_box_light, text_input_default};
+
+use pk_edit::{
+    AnyPokemon, DayCare, GameData, Gen3GameData, Gen3SaveFile, Location, PokemonTrait, SlotRef,
+};
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    GainedExpChanged(usize, String),
+    EggReadyToggled,
+}
+
+pub fn update(
+    daycare: &mut DayCare,
+    save_file: &mut Gen3SaveFile,
+    parents: &[AnyPokemon],
+    message: Message,
+) -> Result<(), SaveDataError> {
+    match message {
+        Message::GainedExpChanged(i, mut value) => {
+            value.retain(|c| c.is_ascii_digit());
+            let parent = parents.get(i).ok_or(SaveDataError::InvalidIndex(i))?;
+            // The counter can't take the parent past level 100.
+            let max = Gen3GameData
+                .exp_for_level(parent.nat_dex_number(), 100)
+                .saturating_sub(parent.experience());
+            let gained_exp = daycare
+                .gained_exp
+                .get_mut(i)
+                .ok_or(SaveDataError::InvalidIndex(i))?;
+            *gained_exp = if value.is_empty() {
+                0
+            } else {
+                value.parse::<u32>().unwrap_or(max).min(max)
+            };
+        }
+        Message::EggReadyToggled => {
+            daycare.egg_ready = !daycare.egg_ready;
+        }
+    }
+    save_file.save_daycare(daycare)
+}
+
+pub fn daycare<'a>(
+    selected: &Option<Id>,
+    parents: &'a [AnyPokemon],
+    daycare: &Option<DayCare>,
+    images: &HashMap<String, image::Handle>,
+    drag: &Option<DragState>,
+) -> Element<'a, message::Message> {
+    let egg_ready = daycare.as_ref().is_some_and(|daycare| daycare.egg_ready);
+
+    let header: Element<'a, Message> = row![
+        text("Day Care"),
+        iced::widget::Space::new().width(Length::Fill),
+        button(text("Egg ready"))
+            .on_press_maybe(daycare.is_some().then_some(Message::EggReadyToggled))
+            .style(if egg_ready {
+                button::primary
+            } else {
+                button::secondary
+            }),
+    ]
+    .padding(Padding::from([0, 10]))
+    .align_y(Alignment::Center)
+    .into();
+
+    let mut slots = row![].spacing(10);
+    for (i, pokemon) in parents.iter().enumerate() {
+        let id = Id::from(format!("daycare_{}", i));
+        let key = sprite_key(pokemon, images);
+        let slot = SlotRef {
+            location: Location::DayCare,
+            index: i,
+        };
+        let slot = if pokemon.is_empty() {
+            party_slot(None, None)
+                .on_press(message::Message::Selected(
+                    Some(id),
+                    Some(slot),
+                    Some(*pokemon),
+                ))
+                .in_drag_mode(drag.is_some())
+                .on_drop(message::Message::DragDrop(slot))
+        } else {
+            party_slot(
+                Some(pokemon),
+                Some(
+                    images
+                        .get(&key)
+                        .unwrap_or({
+                            let width = 10;
+                            let height = 10;
+                            let size = (width * height) as usize;
+                            let pixels = vec![0u8; size * 4];
+                            &image::Handle::from_rgba(width, height, pixels)
+                        })
+                        .clone(),
+                ),
+            )
+            .id(id.clone())
+            .selected(selected)
+            .shiny(pokemon.is_shiny())
+            .on_press(message::Message::Selected(
+                Some(id.clone()),
+                Some(slot),
+                Some(*pokemon),
+            ))
+            .in_drag_mode(drag.is_some())
+            .is_drag_source(drag.as_ref().is_some_and(|d| d.slot == slot))
+            .on_drag_start(move |origin| message::Message::DragStart(slot, origin, key.clone()))
+            .on_drop(message::Message::DragDrop(slot))
+        };
+
+        let gained_exp = daycare
+            .as_ref()
+            .and_then(|daycare| daycare.gained_exp.get(i))
+            .map(u32::to_string)
+            .unwrap_or_default();
+        let counter: Element<'a, Message> = row![
+            text("Gained Exp.").size(12),
+            text_input(&gained_exp, &gained_exp)
+                .on_input(move |input| Message::GainedExpChanged(i, input))
+                .align_x(Horizontal::Center)
+                .style(text_input_default)
+                .width(90)
+                .size(12),
+        ]
+        .align_y(Alignment::Center)
+        .spacing(10)
+        .into();
+
+        slots = slots.push(
+            column![slot, counter.map(message::Message::DayCare)]
+                .align_x(Alignment::Center)
+                .spacing(5),
+        );
+    }
+
+    container(column![header.map(message::Message::DayCare), slots].spacing(10))
+        .style(shadow_box_light)
+        .padding(10)
+        .into()
+}
@@ -0,0 +1,165 @@
+//! An experience progress bar widget.
+//!
+//! [`ExpBar`] renders a fixed-height track of the given width, filled from the
+//! left in proportion to the progress towards the next level, like the blue
+//! bar on the in-game summary screen. Progress is clamped to `0.0..=1.0`.
+
+use iced::advanced::layout::{self, Layout};
+use iced::advanced::renderer;
+use iced::advanced::widget::Tree;
+use iced::advanced::Widget;
+use iced::{color, mouse};
+use iced::{Border, Color, Element, Length, Rectangle, Shadow, Size, Theme};
+
+pub fn exp_bar<'a, Theme>(width: f32, progress: f32) -> ExpBar<'a, Theme>
+where
+    Theme: Catalog + 'a,
+{
+    ExpBar::new(width, progress)
+}
+
+pub struct ExpBar<'a, Theme>
+where
+    Theme: Catalog,
+{
+    size: iced::Size,
+    progress: f32,
+    class: Theme::Class<'a>,
+}
+
+impl<'a, Theme> ExpBar<'a, Theme>
+where
+    Theme: Catalog + 'a,
+{
+    pub fn new(width: f32, progress: f32) -> Self {
+        Self {
+            size: Size::new(width, 8.0),
+            progress: progress.clamp(0.0, 1.0),
+            class: <Theme as Catalog>::default(),
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> Widget<Message, Theme, Renderer> for ExpBar<'a, Theme>
+where
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer,
+{
+    fn size(&self) -> Size<Length> {
+        Size {
+            width: self.size.width.into(),
+            height: self.size.height.into(),
+        }
+    }
+
+    fn layout(
+        &mut self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        _limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::Node::new(self.size)
+    }
+
+    fn draw(
+        &self,
+        _state: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: mouse::Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let style = theme.style(&self.class);
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border: Border {
+                    color: style.border,
+                    width: 1.0,
+                    radius: 4.0.into(),
+                },
+                shadow: Shadow::default(),
+                ..Default::default()
+            },
+            style.track,
+        );
+
+        if self.progress > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        width: bounds.width * self.progress,
+                        ..bounds
+                    },
+                    border: Border {
+                        color: style.border,
+                        width: 1.0,
+                        radius: 4.0.into(),
+                    },
+                    shadow: Shadow::default(),
+                    ..Default::default()
+                },
+                style.fill,
+            );
+        }
+    }
+}
+
+impl<'a, Message, Theme, Renderer> From<ExpBar<'a, Theme>> for Element<'a, Message, Theme, Renderer>
+where
+    Theme: Catalog + 'a,
+    Renderer: iced::advanced::Renderer,
+{
+    fn from(widget: ExpBar<'a, Theme>) -> Self {
+        Self::new(widget)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    pub track: Color,
+
+    pub fill: Color,
+
+    pub border: Color,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            track: color!(0x404040),
+            fill: color!(0x40c8f8),
+            border: color!(0x2890b8),
+        }
+    }
+}
+
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class with the given status.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for an [`ExpBar`].
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(|_| Style::default())
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
@@ -3,13 +3,15 @@
 //! Renders a horizontal bar containing:
 //! - An **Open** button (folder icon) that triggers the file-open dialog.
 //! - A **Save** button (floppy disk icon) that triggers the file-save dialog.
-//! - Tab buttons for switching between the **Party & Boxes** and **Bag & Trainer** screens.
+//! - Tab buttons for switching between the **Party & Boxes**, **Bag & Trainer**,
+//!   **Pokédex**, **Events** and **Hall of Fame** screens.
 
 use iced::advanced::widget::Id;
 use iced::widget::button;
 use iced::widget::container;
 use iced::widget::image;
 use iced::widget::row;
+use iced::widget::svg;
 use iced::widget::text;
 use iced::Element;
 
@@ -17,6 +19,7 @@ use std::collections::HashMap;
 
 use crate::icon;
 use crate::menu_bar_default;
+use crate::misc::PROJECT_DIR;
 use crate::tab;
 use crate::tab_bar_button_primary;
 use crate::tab_bar_tab;
@@ -32,6 +35,17 @@ pub fn view<'a>(
     selected_tab: &Option<Id>,
     images: &HashMap<String, image::Handle>,
 ) -> Element<'a, Message> {
+    let pokedex_icon: Element<'a, Message> = match PROJECT_DIR.get_file("icons/Pokeball_icon.svg") {
+        Some(file) => svg(svg::Handle::from_memory(file.contents()))
+            .width(20.0)
+            .height(20.0)
+            .style(|theme, _| svg::Style {
+                color: text::base(theme).color,
+            })
+            .into(),
+        None => iced::widget::Space::new().into(),
+    };
+
     container(row![
         button(icon::open().center())
             .on_press(Message::OpenFile)
@@ -73,6 +87,26 @@ pub fn view<'a>(
         .style(tab_bar_tab)
         .selected(selected_tab)
         .on_press(Message::SelectedTab(Id::new("2"))),
+        tab(row![
+            pokedex_icon,
+            text("Pokédex").shaping(text::Shaping::Advanced)
+        ]
+        .spacing(5.0)
+        .align_y(iced::alignment::Vertical::Center))
+        .id(Id::new("3"))
+        .style(tab_bar_tab)
+        .selected(selected_tab)
+        .on_press(Message::SelectedTab(Id::new("3"))),
+        tab(text("Events"))
+            .id(Id::new("4"))
+            .style(tab_bar_tab)
+            .selected(selected_tab)
+            .on_press(Message::SelectedTab(Id::new("4"))),
+        tab(text("Hall of Fame"))
+            .id(Id::new("5"))
+            .style(tab_bar_tab)
+            .selected(selected_tab)
+            .on_press(Message::SelectedTab(Id::new("5"))),
     ])
     .style(menu_bar_default)
     .into()
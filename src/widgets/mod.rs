@@ -3,10 +3,15 @@
 //! This module re-exports all custom widget constructors and provides shared
 //! helper functions used across multiple screens:
 //!
-//! - [`move_slot`] — a single move row with type icon, name picker, and PP counter.
+//! - [`move_slot`] — a single move row with type icon, name picker, editable PP,
+//!   PP Up pips and a restore button.
 //! - [`input_level`] — an editable level pill (`Lv. N`).
 //! - [`item_counter`] — a quantity control with `−` / text input / `+` buttons.
+//! - [`sprite_key`] — the image key of a Pokémon's form sprite, or of the egg sprite.
+//! - [`valid_nickname`] — nickname input filtered through the game's text table.
 
+pub mod daycare;
+pub mod exp_bar;
 pub mod gender;
 pub mod level;
 pub mod menu_bar;
@@ -15,10 +20,13 @@ pub mod party_slot;
 pub mod pc;
 pub mod pc_slot;
 pub mod pokemon_info;
+pub mod roamer;
 pub mod slot;
 pub mod stat_bar;
 pub mod tab;
 
+pub use daycare::daycare;
+pub use exp_bar::exp_bar;
 pub use gender::gender;
 use iced::alignment::Horizontal;
 pub use level::level;
@@ -28,6 +36,7 @@ pub use party_slot::party_slot;
 pub use pc::pc_box;
 pub use pc_slot::pc_slot;
 pub use pokemon_info::pokemon_info;
+pub use roamer::roamer;
 pub use stat_bar::stat_bar;
 pub use tab::tab;
 
@@ -40,40 +49,80 @@ use crate::theme::default_box;
 use crate::{bag, item_counter_button_apperance};
 use iced::widget::Container;
 use iced::widget::{button, text_input};
-use iced::widget::{container, image, pick_list, row, text};
-use iced::{Alignment, Element};
+use iced::widget::{container, image, mouse_area, pick_list, row, text};
+use iced::{color, Alignment, Element};
 use pk_edit::Gen3Pocket as Pocket;
+use pk_edit::{AnyPokemon, Move, PokemonTrait};
 
 use crate::pick_list_default;
 use crate::theme::{input_appearance, level_appearance};
 
+/// Drops every character the game's text table can't encode and truncates
+/// the result to the in-game nickname length.
+pub fn valid_nickname(value: &str, max_len: usize, can_encode: impl Fn(char) -> bool) -> String {
+    value
+        .chars()
+        .filter(|c| can_encode(*c))
+        .take(max_len)
+        .collect()
+}
+
+/// Key of the sprite to draw for `pokemon` in the loaded image map.
+///
+/// Alternate forms use `NNNN-Form_Name` sprites and fall back to the
+/// species sprite when the form has none of its own.
+pub fn sprite_key(pokemon: &AnyPokemon, images: &HashMap<String, image::Handle>) -> String {
+    if pokemon.is_egg() {
+        return String::from("egg");
+    }
+    let species_key = format!("{:0width$}", pokemon.nat_dex_number(), width = 4);
+    // Sprite keys are file names, and `?` can't be used in one on Windows.
+    let form = match pokemon.form().as_str() {
+        "!" => String::from("Exclamation"),
+        "?" => String::from("Question"),
+        form => form.replace(' ', "_"),
+    };
+    let form_key = format!("{species_key}-{form}");
+    if images.contains_key(&form_key) {
+        form_key
+    } else {
+        species_key
+    }
+}
+
 pub fn move_slot(
     index: usize,
-    move_type: &str,
-    move_name: &str,
-    pp_used: u8,
-    pp_total: u8,
+    pokemon_move: &Move,
     all_moves: Vec<String>,
     images: &HashMap<String, image::Handle>,
 ) -> Element<'static, pokemon_info::Message> {
-    let move_icon = image(images.get(&format!("{}_icon_SV", move_type)).unwrap_or({
-        let width = 10;
-        let height = 10;
-        let size = (width * height) as usize;
-        let pixels = vec![0u8; size * 4];
-        &image::Handle::from_rgba(width, height, pixels)
-    }))/*.width(45).height(45)*/;
+    let move_icon = image(
+        images
+            .get(&format!("{}_icon_SV", pokemon_move.move_type))
+            .unwrap_or({
+                let width = 10;
+                let height = 10;
+                let size = (width * height) as usize;
+                let pixels = vec![0u8; size * 4];
+                &image::Handle::from_rgba(width, height, pixels)
+            }),
+    );
+    let name = pokemon_move.name.clone();
 
     container(
         row![
             move_icon,
-            pick_list(all_moves, Some(move_name.to_string()), move |selection| {
+            pick_list(all_moves, Some(name), move |selection| {
                 pokemon_info::Message::MoveSelected(index, selection)
             })
             .width(160)
             .style(pick_list_default),
-            pp(pp_used, pp_total),
-            iced::widget::Space::new().width(100)
+            pp(index, pokemon_move),
+            pp_ups(index, pokemon_move.pp_ups),
+            button(text("↺").shaping(text::Shaping::Advanced).center())
+                .on_press(pokemon_info::Message::RestorePp(index))
+                .style(button::subtle)
+                .width(26),
         ]
         .align_y(Alignment::Center)
         .spacing(10),
@@ -85,8 +134,20 @@ pub fn move_slot(
     .into()
 }
 
-fn pp(pp_used: u8, pp_total: u8) -> Container<'static, pokemon_info::Message> {
-    container(text(format!("{}/{}", pp_total.saturating_sub(pp_used), pp_total)))
+fn pp(index: usize, pokemon_move: &Move) -> Container<'static, pokemon_info::Message> {
+    let current = pokemon_move
+        .pp
+        .saturating_sub(pokemon_move.pp_used)
+        .to_string();
+    let input = text_input(&current, &current)
+        .on_input(move |input| pokemon_info::Message::PpChanged(index, input))
+        .line_height(text::LineHeight::Absolute(14.into()))
+        .align_x(Horizontal::Right)
+        .style(input_appearance)
+        .width(25)
+        .size(14);
+
+    container(row![input, text(format!("/{}", pokemon_move.pp))].align_y(Alignment::Center))
         .width(60)
         .height(30.0)
         .align_y(iced::alignment::Vertical::Center)
@@ -94,6 +155,26 @@ fn pp(pp_used: u8, pp_total: u8) -> Container<'static, pokemon_info::Message> {
         .style(default_box)
 }
 
+/// Three pips for the PP Ups applied to a move. Clicking the highest filled
+/// pip removes it, clicking any other pip fills up to it.
+fn pp_ups(index: usize, applied: u8) -> Element<'static, pokemon_info::Message> {
+    let mut pips = row![].spacing(2).align_y(Alignment::Center);
+    for pip in 1..=3_u8 {
+        let target = if pip == applied { pip - 1 } else { pip };
+        pips = pips.push(
+            mouse_area(
+                text(if pip <= applied { "●" } else { "○" })
+                    .shaping(text::Shaping::Advanced)
+                    .color(color!(0xffcc00))
+                    .size(12),
+            )
+            .interaction(iced::mouse::Interaction::Pointer)
+            .on_press(pokemon_info::Message::PpUpsChanged(index, target)),
+        );
+    }
+    pips.into()
+}
+
 pub fn input_level(level: u8) -> Container<'static, pokemon_info::Message> {
     let input = text_input(&level.to_string(), &level.to_string())
         .on_input(pokemon_info::Message::LevelInputChanged)
@@ -13,10 +13,10 @@ use std::collections::HashMap;
 
 use crate::message::Message;
 use crate::misc::PROJECT_DIR;
-use crate::widgets::party_slot;
+use crate::widgets::{party_slot, sprite_key};
 use crate::DragState;
 
-use pk_edit::{AnyPokemon, PokemonTrait, StorageType};
+use pk_edit::{AnyPokemon, Location, PokemonTrait, SlotRef};
 
 pub fn party_label<'a>() -> Element<'a, Message> {
     let handle = iced::widget::svg::Handle::from_memory(
@@ -89,53 +89,45 @@ pub fn party<'a>(
 
     for i in 0..6 {
         let id = Id::from(format!("party_{}", i));
+        let slot = SlotRef {
+            location: Location::Party,
+            index: i,
+        };
         match party.get(i) {
             Some(pokemon) if !pokemon.is_empty() => {
-                col = col.push(
-                    party_slot(
-                        Some(pokemon),
-                        Some(
-                            images
-                                .get(&format!("{:0width$}", pokemon.nat_dex_number(), width = 4))
-                                .unwrap_or({
-                                    let width = 10;
-                                    let height = 10;
-                                    let size = (width * height) as usize;
-                                    let pixels = vec![0u8; size * 4];
-                                    &image::Handle::from_rgba(width, height, pixels)
-                                })
-                                .clone(),
-                        ),
-                    )
-                    .id(id.clone())
-                    .selected(selected)
-                    .on_press(Message::Selected(
-                        Some(id.clone()),
-                        Some(StorageType::Party),
-                        Some(*pokemon),
-                    ))
-                    .in_drag_mode(drag.is_some())
-                    .is_drag_source(
-                        drag.as_ref().is_some_and(|d| {
-                            d.index == i && matches!(d.storage, StorageType::Party)
-                        }),
-                    )
-                    .on_drag_start(move |origin| {
-                        Message::DragStart(StorageType::Party, origin, pokemon.nat_dex_number(), i)
+                let key = sprite_key(pokemon, images);
+                let handle = images
+                    .get(&key)
+                    .unwrap_or({
+                        let width = 10;
+                        let height = 10;
+                        let size = (width * height) as usize;
+                        let pixels = vec![0u8; size * 4];
+                        &image::Handle::from_rgba(width, height, pixels)
                     })
-                    .on_drop(Message::DragDrop(StorageType::Party, i)),
+                    .clone();
+                col = col.push(
+                    party_slot(Some(pokemon), Some(handle))
+                        .id(id.clone())
+                        .selected(selected)
+                        .shiny(pokemon.is_shiny())
+                        .on_press(Message::Selected(
+                            Some(id.clone()),
+                            Some(slot),
+                            Some(*pokemon),
+                        ))
+                        .in_drag_mode(drag.is_some())
+                        .is_drag_source(drag.as_ref().is_some_and(|d| d.slot == slot))
+                        .on_drag_start(move |origin| Message::DragStart(slot, origin, key.clone()))
+                        .on_drop(Message::DragDrop(slot)),
                 );
             }
             Some(pokemon) => {
                 col = col.push(
                     party_slot(None, None)
-                        .on_press(Message::Selected(
-                            Some(id),
-                            Some(StorageType::Party),
-                            Some(*pokemon),
-                        ))
+                        .on_press(Message::Selected(Some(id), Some(slot), Some(*pokemon)))
                         .in_drag_mode(drag.is_some())
-                        .on_drop(Message::DragDrop(StorageType::Party, i)),
+                        .on_drop(Message::DragDrop(slot)),
                 );
             }
             None => {
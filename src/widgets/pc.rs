@@ -1,37 +1,55 @@
 //! Composite widget rendering a PC box.
 //!
 //! Provides two functions:
-//! - [`pc_box`] — a 6 × 5 grid of [`crate::widgets::pc_slot`] widgets for the 30 slots of one box.
+//! - [`pc_box`] — a grid of [`crate::widgets::pc_slot`] widgets for the slots of one box,
+//!   laid out in the save's box geometry (6 × 5 in Gen III).
 //! - `pc_label` (private) — a header row with left/right navigation buttons and the current box number.
 //!
 //! Box navigation emits [`crate::Message::Increment`] and [`crate::Message::Decrement`].
+//! While dragging, hovering an arrow flips the box so Pokémon can be dropped into another one.
 
 use iced::advanced::widget::Id;
 use iced::widget::image;
-use iced::widget::{button, column, row};
+use iced::widget::{button, column, mouse_area, row};
 use iced::Element;
 
-use itertools::Itertools;
 use std::collections::HashMap;
 
 use crate::DragState;
 use crate::Message;
-use crate::{icon, pc_slot};
+use crate::{icon, pc_slot, sprite_key};
 use crate::{shadow_box, tab_bar_button_primary};
 
-use pk_edit::StorageType;
-use pk_edit::{AnyPokemon, PokemonTrait};
+use pk_edit::{AnyPokemon, Location, PokemonTrait, SlotRef};
+
+/// Wraps a navigation arrow so hovering it during a drag sends `message`.
+fn flip_on_hover<'a>(
+    arrow: impl Into<Element<'a, Message>>,
+    message: Message,
+    dragging: bool,
+) -> Element<'a, Message> {
+    if dragging {
+        mouse_area(arrow).on_enter(message).into()
+    } else {
+        arrow.into()
+    }
+}
 
 pub fn pc_label<'a>(
     pc_i: usize,
     images: &HashMap<String, image::Handle>,
+    dragging: bool,
     scale: f32,
 ) -> Element<'a, Message> {
     row![
-        button(icon::left().size(25.0 * scale).center())
-            .on_press(Message::Decrement)
-            .height(40.0 * scale)
-            .style(tab_bar_button_primary),
+        flip_on_hover(
+            button(icon::left().size(25.0 * scale).center())
+                .on_press(Message::Decrement)
+                .height(40.0 * scale)
+                .style(tab_bar_button_primary),
+            Message::Decrement,
+            dragging,
+        ),
         iced::widget::container(
             row![
                 image(images.get("pokebox_icon").unwrap_or({
@@ -52,10 +70,14 @@ pub fn pc_label<'a>(
         .align_y(iced::alignment::Vertical::Center)
         .align_x(iced::alignment::Horizontal::Center)
         .style(shadow_box),
-        button(icon::right().size(25.0 * scale).center())
-            .on_press(Message::Increment)
-            .height(40.0 * scale)
-            .style(tab_bar_button_primary),
+        flip_on_hover(
+            button(icon::right().size(25.0 * scale).center())
+                .on_press(Message::Increment)
+                .height(40.0 * scale)
+                .style(tab_bar_button_primary),
+            Message::Increment,
+            dragging,
+        ),
     ]
     .spacing(10.0 * scale)
     .align_y(iced::alignment::Vertical::Center)
@@ -65,6 +87,7 @@ pub fn pc_label<'a>(
 pub fn pc_box<'a>(
     selected: &Option<Id>,
     pc_i: &usize,
+    box_size: (usize, usize),
     pc_list: &'a [AnyPokemon],
     images: &HashMap<String, image::Handle>,
     drag: &Option<DragState>,
@@ -73,15 +96,26 @@ pub fn pc_box<'a>(
     let mut col = iced::widget::Column::new()
         .align_x(iced::Alignment::Center)
         .spacing(10.0 * scale);
-    for chunk in pc_list.iter().enumerate().chunks(6).into_iter() {
+    let (columns, rows) = box_size;
+    let box_len = columns.saturating_mul(rows);
+    for row_start in (0..rows).map(|row| row * columns) {
         let mut pc_row = iced::widget::Row::new().spacing(10.0 * scale);
-        for (index, pokemon) in chunk {
-            let global_index = pc_i * pc_list.len() + index;
+        for index in row_start..row_start + columns {
+            // A short box read leaves the rest of the grid out.
+            let Some(pokemon) = pc_list.get(index) else {
+                break;
+            };
+            let global_index = pc_i * box_len + index;
             let id = Id::from(format!("pc-{global_index}"));
+            let key = sprite_key(pokemon, images);
+            let slot = SlotRef {
+                location: Location::Box(*pc_i),
+                index,
+            };
             pc_row = pc_row.push(if !pokemon.is_empty() {
                 pc_slot(Some(
                     images
-                        .get(&format!("{:0width$}", pokemon.nat_dex_number(), width = 4))
+                        .get(&key)
                         .unwrap_or({
                             let width = 10;
                             let height = 10;
@@ -94,36 +128,28 @@ pub fn pc_box<'a>(
                 .scale(scale)
                 .id(id.clone())
                 .selected(selected)
+                .shiny(pokemon.is_shiny())
                 .in_drag_mode(drag.is_some())
-                .is_drag_source(
-                    drag.as_ref()
-                        .is_some_and(|d| d.index == index && matches!(d.storage, StorageType::PC)),
-                )
+                .is_drag_source(drag.as_ref().is_some_and(|d| d.slot == slot))
                 .on_press(Message::Selected(
                     Some(id.clone()),
-                    Some(StorageType::PC),
+                    Some(slot),
                     Some(*pokemon),
                 ))
-                .on_drag_start(move |origin| {
-                    Message::DragStart(StorageType::PC, origin, pokemon.nat_dex_number(), index)
-                })
-                .on_drop(Message::DragDrop(StorageType::PC, index))
+                .on_drag_start(move |origin| Message::DragStart(slot, origin, key.clone()))
+                .on_drop(Message::DragDrop(slot))
             } else {
                 pc_slot(None)
                     .scale(scale)
-                    .on_press(Message::Selected(
-                        Some(id),
-                        Some(StorageType::PC),
-                        Some(*pokemon),
-                    ))
+                    .on_press(Message::Selected(Some(id), Some(slot), Some(*pokemon)))
                     .in_drag_mode(drag.is_some())
-                    .on_drop(Message::DragDrop(StorageType::PC, index))
+                    .on_drop(Message::DragDrop(slot))
             });
         }
         col = col.push(pc_row);
     }
 
-    column![pc_label(pc_i + 1, images, scale), col,]
+    column![pc_label(pc_i + 1, images, drag.is_some(), scale), col,]
         .align_x(iced::Alignment::Center)
         .spacing(15.0 * scale)
         .into()
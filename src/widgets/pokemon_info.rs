@@ -3,15 +3,36 @@
 //! Occupies the right third of the **Party & Boxes** screen when a Pokémon is selected.
 //! The panel is divided into scrollable sections:
 //!
-//! 1. **Header** — Pokéball picker, nickname, Pokérus toggle, level input, gender badge.
-//! 2. **Species** — National Dex number, species combo box, language.
-//! 3. **Typing** — type icon(s) for the selected species.
+//! 1. **Header** — Pokéball picker, editable nickname with a special-glyph palette,
+//!    shiny star toggle, Pokérus toggle, level input, gender badge.
+//! 2. **Species** — National Dex number, species combo box, **Legal** mode toggle,
+//!    language, legality badge listing every finding of [`pk_edit::legality::check`]
+//!    (the report is computed in `update` and passed in).
+//!    In legal mode a row of encounter settings (method, encounter type, met
+//!    location, level, nature, shiny) appears below, and picking a species runs
+//!    [`PokemonFactoryTrait::gen_legal_pokemon`] instead of the raw factory.
+//!    Species with alternate forms get a form picker (Unown letters, the
+//!    current game's Deoxys form) and Spinda a button to reroll its spots.
+//! 3. **Typing** — type icon(s) for the selected species, followed by an
+//!    **Experience** row: total experience input, the growth rate, the points
+//!    left to the next level and a [`crate::widgets::exp_bar`].
 //! 4. **Stats** — `HP` / `Atk` / `Def` / `SpA` / `SpD` / `Spe` with colour-coded bars and IV/EV inputs.
-//! 5. **OT info** — original trainer name and ID (read-only).
-//! 6. **PID / Friendship** — personality value (read-only display) and editable friendship.
+//!    Party Pokémon also get a **Battle** row: status condition (with sleep
+//!    turns), current HP and a button to recompute the cached battle stats.
+//!    A **Pokérus** row edits the strain and days left and warns about
+//!    combinations the game never produces.
+//! 5. **OT info** — original trainer name (read-only) and a clickable OT gender.
+//!    Followed by **Origin** — origin game, met location and level, fateful
+//!    encounter flag, box markings and an in-game style memo line.
+//! 6. **PID / Friendship** — personality value (read-only display) and editable friendship,
+//!    or the remaining egg cycles for an egg.
 //! 7. **Nature / Ability** — nature pick list and ability text.
 //! 8. **Held Item** — item sprite and pick list.
-//! 9. **Moves** — up to four [`crate::widgets::move_slot`] rows.
+//! 9. **Moves** — up to four [`crate::widgets::move_slot`] rows with current PP, PP Ups
+//!    and a restore button, and an **Add move** picker for each empty slot.
+//! 10. **Contest** — Cool / Beauty / Cute / Smart / Tough / Sheen inputs and a
+//!     grid of ribbon icons that toggle on click, with **Grant legal** and
+//!     **Clear** actions.
 //!
 //! Owns its own [`Message`] enum and [`update`] function which validate inputs
 //! and mutate the selected [`pk_edit::pokemon::Pokemon`].
@@ -20,53 +41,244 @@ use iced::alignment::Horizontal;
 use iced::color;
 use iced::widget::{button, text_input};
 use iced::widget::{
-    column, combo_box, container, image, mouse_area, pick_list, row, scrollable, text,
+    column, combo_box, container, image, mouse_area, pick_list, row, scrollable, text, tooltip,
 };
 use iced::{Alignment, Element, Length};
+use pk_edit::encounter::{EncounterRequest, EncounterType, Method};
 use pk_edit::error::PokemonError;
+use pk_edit::legality::{LegalityReport, Severity};
 use pk_edit::{
-    AnyFactory, AnyGameData, AnyPokemon, ComputedStats, GameData, PokemonFactoryTrait,
-    PokemonTrait, Pokerus, StatBlock, TrainerID, NATURE,
+    AnyFactory, AnyGameData, AnyPokemon, ComputedStats, GameData, GameVersion, Gender, Move,
+    PokemonFactoryTrait, PokemonTrait, Pokerus, PokerusState, Ribbon, StatBlock, Status, TrainerID,
+    NATURE,
 };
 use widgets::generic_overlay::dropdown_root;
 
 use std::collections::HashMap;
+use std::fmt;
 
-use crate::theme::{info_label_appearance, pokeball_picker_apperance, pokemon_info_appearance};
+use crate::theme::{
+    default_box, illegal_badge_appearance, info_label_appearance, legal_badge_appearance,
+    nickname_input_appearance, pokeball_picker_apperance, pokemon_info_appearance, toggle_style,
+};
 use crate::widgets::input_level;
 
 use crate::stat_bar;
+use crate::widgets::exp_bar;
 use crate::widgets::gender;
 use crate::widgets::move_slot;
+use crate::widgets::valid_nickname;
 use crate::{pick_list_default, text_input_default};
 
+/// Glyphs that can't be typed on a regular keyboard but exist in the in-game text table.
+const NICKNAME_GLYPHS: [char; 9] = ['♂', '♀', '…', '“', '”', '‘', '’', '·', '×'];
+
 fn w(width: f32) -> iced::Length {
     iced::Length::Fixed(width)
 }
 
+/// [`valid_nickname`] with the Pokémon's own text table and nickname length.
+fn pokemon_nickname(pokemon: &AnyPokemon, value: &str) -> String {
+    valid_nickname(value, pokemon.nickname_max_len(), |c| {
+        pokemon.can_encode_char(c)
+    })
+}
+
+/// Gives a Pokémon whose nickname was cleared its species name back.
+///
+/// The game never stores an empty nickname. The info panel lets the field be
+/// empty while typing, so this runs on submit and on every copy written to the save.
+///
+/// # Errors
+///
+/// Returns a [`PokemonError`] if the species name can't be encoded.
+pub fn fill_empty_nickname(pokemon: &mut AnyPokemon) -> Result<(), PokemonError> {
+    if pokemon.is_empty() || !pokemon.nickname().is_empty() {
+        return Ok(());
+    }
+    let default = pokemon_nickname(pokemon, &pokemon.species().to_uppercase());
+    pokemon.set_nickname(&default)
+}
+
+/// The lowest stage of the Pokémon's evolution family, which its eggs hatch into.
+fn base_species(pokemon: &AnyPokemon, game_data: &AnyGameData) -> String {
+    let chain = game_data
+        .evolution_chain(pokemon.nat_dex_number())
+        .unwrap_or_default();
+    let mut species = pokemon.species();
+    // Bounded by the chain length, each step goes back one stage.
+    for _ in &chain {
+        match chain.iter().find(|evolution| evolution.to == species) {
+            Some(evolution) => species.clone_from(&evolution.from),
+            None => break,
+        }
+    }
+    species
+}
+
+/// Settings of the species combo box's "generate legal" mode.
+#[derive(Debug, Clone)]
+pub struct LegalMode {
+    pub enabled: bool,
+    pub level: u8,
+    /// The met location ID, `None` lets the generator pick any location the
+    /// species appears at.
+    pub met_location: Option<u8>,
+    pub encounter_type: EncounterType,
+    pub method: Method,
+    /// `None` accepts whatever nature the RNG frame produces.
+    pub nature: Option<String>,
+    pub shiny: bool,
+    /// The save's game, whose met location table the generator uses.
+    pub version: Option<GameVersion>,
+}
+
+impl Default for LegalMode {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            level: 5,
+            met_location: None,
+            encounter_type: EncounterType::Grass,
+            method: Method::One,
+            nature: None,
+            shiny: false,
+            version: None,
+        }
+    }
+}
+
+const ANY: &str = "Any";
+
+const CONTEST_STATS: [&str; 6] = ["Cool", "Beauty", "Cute", "Smart", "Tough", "Sheen"];
+
+/// Spinda's spot pattern comes from its PID and has no named forms.
+const SPINDA: u16 = 327;
+
+/// An entry of the met location picker. Several IDs share a name, so the
+/// picker works with the ID instead of looking the name up again.
+#[derive(Debug, Clone, PartialEq)]
+struct MetLocation {
+    id: u8,
+    name: String,
+}
+
+impl fmt::Display for MetLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// An entry of the legal mode's met location picker.
+#[derive(Debug, Clone, PartialEq)]
+enum LegalMetLocation {
+    Any,
+    Location(MetLocation),
+}
+
+impl LegalMetLocation {
+    fn id(&self) -> Option<u8> {
+        match self {
+            Self::Any => None,
+            Self::Location(location) => Some(location.id),
+        }
+    }
+}
+
+impl fmt::Display for LegalMetLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Any => write!(f, "{ANY}"),
+            Self::Location(location) => write!(f, "{location}"),
+        }
+    }
+}
+
+/// The met locations of a game, location IDs mean different places depending on the game.
+fn met_locations(game_data: &AnyGameData, version: GameVersion) -> Vec<MetLocation> {
+    game_data
+        .met_location_names(version)
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(id, name)| {
+            let id = u8::try_from(id).ok()?;
+            Some(MetLocation { id, name })
+        })
+        .collect()
+}
+
+/// Box markings in the order of their bits.
+const MARKINGS: [&str; 4] = ["●", "■", "▲", "♥"];
+
 #[derive(Debug, Clone)]
 pub enum Message {
-    AddMove(usize),
     ChangePokerusStatus,
+    PokerusStrainChanged(String),
+    PokerusDaysChanged(String),
     ChangePokeball(u8),
+    ShinyToggled,
+    FormSelected(String),
+    RerollForm,
+    NicknameChanged(String),
+    NicknameSubmitted,
+    NicknameGlyphSelected(char),
     NatureSelected(String),
     SpeciesSelected(String),
     HeldItemSelected(String),
     FriendshipChanged(String),
+    EggCyclesChanged(String),
+    MakeEgg(String),
+    HatchNow,
     LevelInputChanged(String),
+    ExperienceChanged(String),
+    ExperienceSubmitted,
     IVChanged(String, String),
     EVChanged(String, String),
     MoveSelected(usize, String),
+    PpChanged(usize, String),
+    PpUpsChanged(usize, u8),
+    RestorePp(usize),
+    LegalModeToggled,
+    LegalLevelChanged(String),
+    LegalMetLocationSelected(Option<u8>),
+    LegalEncounterTypeSelected(EncounterType),
+    LegalMethodSelected(Method),
+    LegalNatureSelected(String),
+    LegalShinyToggled,
+    ContestStatChanged(String, String),
+    StatusSelected(Status),
+    SleepTurnsChanged(String),
+    CurrentHpChanged(String),
+    RecomputeStats,
+    OriginGameSelected(GameVersion),
+    MetLocationSelected(u8),
+    MetLevelChanged(String),
+    OtGenderToggled,
+    FatefulEncounterToggled,
+    MarkingToggled(usize),
+    RibbonToggled(Ribbon),
+    GrantLegalRibbons,
+    ClearRibbons,
 }
 
 pub fn update(
     selected_pokemon: &mut Option<AnyPokemon>,
+    legal_mode: &mut LegalMode,
+    experience_input: &mut Option<String>,
     factory: &AnyFactory,
     game_data: &AnyGameData,
     ot_name: &str,
     ot_id: TrainerID,
     message: Message,
 ) -> Result<(), PokemonError> {
+    // Any other edit may change the experience, show the saved value again.
+    if !matches!(
+        message,
+        Message::ExperienceChanged(_) | Message::ExperienceSubmitted
+    ) {
+        experience_input.take();
+    }
     match message {
         Message::ChangePokeball(ball_id) => {
             if let Some(selected_pokemon) = selected_pokemon.as_mut() {
@@ -76,17 +288,86 @@ pub fn update(
         }
         Message::ChangePokerusStatus => {
             if let Some(selected_pokemon) = selected_pokemon.as_mut() {
-                match selected_pokemon.pokerus_status() {
-                    Pokerus::Infected => {
-                        selected_pokemon.cure_pokerus();
-                    }
-                    Pokerus::Cured => {
-                        selected_pokemon.remove_pokerus();
-                    }
-                    Pokerus::None => {
-                        selected_pokemon.infect_pokerus();
-                    }
-                }
+                let pokerus = selected_pokemon.pokerus();
+                let next = match pokerus.status() {
+                    Pokerus::Infected => pokerus.cured(),
+                    Pokerus::Cured => PokerusState::default(),
+                    Pokerus::None => PokerusState::infected(1),
+                };
+                selected_pokemon.set_pokerus(next);
+            }
+            Ok(())
+        }
+        Message::PokerusStrainChanged(mut value) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                value.retain(|c| c.is_numeric());
+                // Both halves of the byte are nibbles.
+                let strain = value.parse::<u8>().unwrap_or(0).min(15);
+                let pokerus = PokerusState {
+                    strain,
+                    ..selected_pokemon.pokerus()
+                };
+                // A shorter-lived strain also shortens the days left.
+                let days = pokerus.days.min(pokerus.max_days());
+                selected_pokemon.set_pokerus(PokerusState { days, ..pokerus });
+            }
+            Ok(())
+        }
+        Message::PokerusDaysChanged(mut value) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                value.retain(|c| c.is_numeric());
+                let pokerus = selected_pokemon.pokerus();
+                // The strain decides how long an infection lasts.
+                let days = if value.is_empty() {
+                    0
+                } else {
+                    value
+                        .parse::<u8>()
+                        .unwrap_or(u8::MAX)
+                        .min(pokerus.max_days())
+                };
+                selected_pokemon.set_pokerus(PokerusState { days, ..pokerus });
+            }
+            Ok(())
+        }
+        Message::ShinyToggled => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                let shiny = !selected_pokemon.is_shiny();
+                selected_pokemon.set_shiny(shiny)?;
+            }
+            Ok(())
+        }
+        Message::FormSelected(form) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                selected_pokemon.set_form(&form)?;
+            }
+            Ok(())
+        }
+        Message::RerollForm => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                selected_pokemon.reroll_form()?;
+            }
+            Ok(())
+        }
+        Message::NicknameChanged(value) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                let nickname = pokemon_nickname(selected_pokemon, &value);
+                selected_pokemon.set_nickname(&nickname)?;
+            }
+            Ok(())
+        }
+        Message::NicknameSubmitted => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                fill_empty_nickname(selected_pokemon)?;
+            }
+            Ok(())
+        }
+        Message::NicknameGlyphSelected(glyph) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                let mut nickname = selected_pokemon.nickname();
+                nickname.push(glyph);
+                let nickname = pokemon_nickname(selected_pokemon, &nickname);
+                selected_pokemon.set_nickname(&nickname)?;
             }
             Ok(())
         }
@@ -107,9 +388,58 @@ pub fn update(
             }
             Ok(())
         }
+        Message::ExperienceChanged(mut value) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                value.retain(|c| c.is_numeric());
+                let dex_number = selected_pokemon.nat_dex_number();
+                let lowest =
+                    game_data.exp_for_level(dex_number, game_data.lowest_level(dex_number));
+                let highest = game_data.exp_for_level(dex_number, 100);
+                if let Ok(number) = value.parse::<u64>() {
+                    let experience = u32::try_from(number).unwrap_or(highest).min(highest);
+                    // Below the minimum only the text is kept, so the digits of a number
+                    // can be typed one by one without saving a lower level in between.
+                    if experience >= lowest {
+                        // Also moves the level to the one this much experience reaches.
+                        selected_pokemon.set_experience(experience)?;
+                        value = experience.to_string();
+                    }
+                }
+                experience_input.replace(value);
+            }
+            Ok(())
+        }
+        Message::ExperienceSubmitted => {
+            if let (Some(selected_pokemon), Some(value)) =
+                (selected_pokemon.as_mut(), experience_input.take())
+            {
+                let dex_number = selected_pokemon.nat_dex_number();
+                let lowest =
+                    game_data.exp_for_level(dex_number, game_data.lowest_level(dex_number));
+                if !value
+                    .parse::<u64>()
+                    .is_ok_and(|number| number >= u64::from(lowest))
+                {
+                    selected_pokemon.set_experience(lowest)?;
+                }
+            }
+            Ok(())
+        }
         Message::SpeciesSelected(species) => {
             if let Some(selected_pokemon) = selected_pokemon.as_mut() {
-                if selected_pokemon.is_empty() {
+                if legal_mode.enabled {
+                    let request = EncounterRequest {
+                        species,
+                        level: legal_mode.level,
+                        met_location: legal_mode.met_location,
+                        encounter_type: legal_mode.encounter_type,
+                        method: legal_mode.method,
+                        nature: legal_mode.nature.clone(),
+                        shiny: legal_mode.shiny,
+                    };
+                    *selected_pokemon =
+                        factory.gen_legal_pokemon(&selected_pokemon, &request, ot_name, ot_id)?;
+                } else if selected_pokemon.is_empty() {
                     *selected_pokemon = factory.gen_pokemon_from_species(
                         &selected_pokemon,
                         &species,
@@ -158,6 +488,37 @@ pub fn update(
             }
             Ok(())
         }
+        Message::EggCyclesChanged(mut value) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                value.retain(|c| c.is_numeric());
+                if let Ok(number) = value.parse::<u64>() {
+                    let value = u8::try_from(number).unwrap_or(u8::MAX);
+                    selected_pokemon.set_egg_cycles(value)?;
+                }
+            }
+            Ok(())
+        }
+        Message::MakeEgg(species) => {
+            // Eggs only go into empty slots, an occupied one is never overwritten.
+            if let Some(selected_pokemon) = selected_pokemon.as_mut().filter(|p| p.is_empty()) {
+                let egg = factory.gen_egg(&selected_pokemon, &species, ot_name, ot_id)?;
+                let base = base_species(&egg, game_data);
+                *selected_pokemon = if base == species {
+                    egg
+                } else {
+                    factory.gen_egg(&selected_pokemon, &base, ot_name, ot_id)?
+                };
+            }
+            Ok(())
+        }
+        Message::HatchNow => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                if selected_pokemon.is_egg() {
+                    selected_pokemon.hatch()?;
+                }
+            }
+            Ok(())
+        }
         Message::HeldItemSelected(item) => {
             if let Some(selected_pokemon) = selected_pokemon.as_mut() {
                 selected_pokemon.set_held_item(&item)?;
@@ -170,9 +531,39 @@ pub fn update(
             }
             Ok(())
         }
-        Message::AddMove(index) => {
+        Message::PpChanged(index, mut value) => {
             if let Some(selected_pokemon) = selected_pokemon.as_mut() {
-                selected_pokemon.set_move(index, "Pound")?;
+                value.retain(|c| c.is_numeric());
+                let max_pp = selected_pokemon
+                    .moves()
+                    .get(index)
+                    .map_or(0, |pokemon_move| pokemon_move.pp);
+                let pp = if value.is_empty() {
+                    0
+                } else if let Ok(number) = value.parse::<u64>() {
+                    u8::try_from(number.min(u64::from(max_pp))).unwrap_or(max_pp)
+                } else {
+                    max_pp
+                };
+                selected_pokemon.set_current_pp(index, pp)?;
+            }
+            Ok(())
+        }
+        Message::PpUpsChanged(index, pp_ups) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                selected_pokemon.set_pp_ups(index, pp_ups.min(3))?;
+            }
+            Ok(())
+        }
+        Message::RestorePp(index) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                if let Some(max_pp) = selected_pokemon
+                    .moves()
+                    .get(index)
+                    .map(|pokemon_move| pokemon_move.pp)
+                {
+                    selected_pokemon.set_current_pp(index, max_pp)?;
+                }
             }
             Ok(())
         }
@@ -182,9 +573,257 @@ pub fn update(
             }
             Ok(())
         }
+        Message::LegalModeToggled => {
+            legal_mode.enabled = !legal_mode.enabled;
+            Ok(())
+        }
+        Message::LegalLevelChanged(mut value) => {
+            value.retain(|c| c.is_numeric());
+            legal_mode.level = value.parse::<u8>().unwrap_or(1).clamp(1, 100);
+            Ok(())
+        }
+        Message::LegalMetLocationSelected(location) => {
+            legal_mode.met_location = location;
+            Ok(())
+        }
+        Message::LegalEncounterTypeSelected(encounter_type) => {
+            legal_mode.encounter_type = encounter_type;
+            Ok(())
+        }
+        Message::LegalMethodSelected(method) => {
+            legal_mode.method = method;
+            Ok(())
+        }
+        Message::LegalNatureSelected(nature) => {
+            legal_mode.nature = (nature != ANY).then_some(nature);
+            Ok(())
+        }
+        Message::LegalShinyToggled => {
+            legal_mode.shiny = !legal_mode.shiny;
+            Ok(())
+        }
+        Message::ContestStatChanged(stat, mut value) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                value.retain(|c| c.is_numeric());
+                if let Ok(number) = value.parse::<u64>() {
+                    let value = u8::try_from(number).unwrap_or(u8::MAX);
+                    selected_pokemon.set_contest_stat(&stat, value)?;
+                } else if value.is_empty() {
+                    selected_pokemon.set_contest_stat(&stat, 0)?;
+                }
+            }
+            Ok(())
+        }
+        Message::StatusSelected(status) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                selected_pokemon.set_status(status)?;
+            }
+            Ok(())
+        }
+        Message::SleepTurnsChanged(mut value) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                value.retain(|c| c.is_numeric());
+                // Sleep lasts 1 to 7 turns, stored in the low three status bits.
+                let turns = value.parse::<u8>().unwrap_or(1).clamp(1, 7);
+                selected_pokemon.set_sleep_turns(turns)?;
+            }
+            Ok(())
+        }
+        Message::CurrentHpChanged(mut value) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                value.retain(|c| c.is_numeric());
+                let max_hp = selected_pokemon.computed_stats().hp;
+                let hp = if value.is_empty() {
+                    0
+                } else if let Ok(number) = value.parse::<u64>() {
+                    u16::try_from(number.min(u64::from(max_hp))).unwrap_or(max_hp)
+                } else {
+                    max_hp
+                };
+                selected_pokemon.set_current_hp(hp)?;
+            }
+            Ok(())
+        }
+        Message::RecomputeStats => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                selected_pokemon.recompute_stats()?;
+            }
+            Ok(())
+        }
+        Message::OriginGameSelected(version) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                selected_pokemon.set_origin_game(version)?;
+            }
+            Ok(())
+        }
+        Message::MetLocationSelected(id) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                selected_pokemon.set_met_location(id)?;
+            }
+            Ok(())
+        }
+        Message::MetLevelChanged(mut value) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                value.retain(|c| c.is_numeric());
+                if let Ok(number) = value.parse::<u64>() {
+                    // Met level 0 marks a Pokémon hatched from an egg.
+                    let value = u8::try_from(number.min(100)).unwrap_or(100);
+                    selected_pokemon.set_met_level(value)?;
+                }
+            }
+            Ok(())
+        }
+        Message::OtGenderToggled => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                let gender = match selected_pokemon.ot_gender() {
+                    Gender::M => Gender::F,
+                    Gender::F | Gender::None => Gender::M,
+                };
+                selected_pokemon.set_ot_gender(gender)?;
+            }
+            Ok(())
+        }
+        Message::FatefulEncounterToggled => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                let fateful = selected_pokemon.fateful_encounter();
+                selected_pokemon.set_fateful_encounter(!fateful)?;
+            }
+            Ok(())
+        }
+        Message::MarkingToggled(i) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                let marked = selected_pokemon.markings().get(i).copied().unwrap_or(false);
+                selected_pokemon.set_marking(i, !marked)?;
+            }
+            Ok(())
+        }
+        Message::RibbonToggled(ribbon) => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                let has_ribbon = selected_pokemon.has_ribbon(ribbon);
+                selected_pokemon.set_ribbon(ribbon, !has_ribbon)?;
+            }
+            Ok(())
+        }
+        Message::GrantLegalRibbons => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                selected_pokemon.grant_legal_ribbons(game_data)?;
+            }
+            Ok(())
+        }
+        Message::ClearRibbons => {
+            if let Some(selected_pokemon) = selected_pokemon.as_mut() {
+                for ribbon in Ribbon::ALL {
+                    selected_pokemon.set_ribbon(ribbon, false)?;
+                }
+            }
+            Ok(())
+        }
     }
 }
 
+fn form<'a>(pokemon: &AnyPokemon, game_data: &AnyGameData) -> Element<'a, Message> {
+    let forms = game_data
+        .forms(pokemon.nat_dex_number())
+        .unwrap_or_default();
+
+    let picker: Element<'a, Message> = if forms.len() > 1 {
+        pick_list(forms, Some(pokemon.form()), Message::FormSelected)
+            .width(130)
+            .style(pick_list_default)
+            .into()
+    } else if pokemon.nat_dex_number() == SPINDA {
+        button(text("Reroll spots").size(12))
+            .on_press(Message::RerollForm)
+            .style(button::secondary)
+            .into()
+    } else if !pokemon.form().is_empty() {
+        // Forms the save can't change, like Deoxys outside its own game.
+        text(pokemon.form()).into()
+    } else {
+        return row![].into();
+    };
+
+    row![text("Form").color(color!(0xffcc00)), picker]
+        .spacing(20)
+        .align_y(Alignment::Center)
+        .padding([5, 15])
+        .into()
+}
+
+fn legal_settings<'a>(legal_mode: &LegalMode, game_data: &AnyGameData) -> Element<'a, Message> {
+    let mut locations = vec![LegalMetLocation::Any];
+    if let Some(version) = legal_mode.version {
+        locations.extend(
+            met_locations(game_data, version)
+                .into_iter()
+                .map(LegalMetLocation::Location),
+        );
+    }
+    let location = locations
+        .iter()
+        .find(|location| location.id() == legal_mode.met_location)
+        .cloned()
+        .unwrap_or(LegalMetLocation::Any);
+    let mut natures = vec![String::from(ANY)];
+    natures.extend(NATURE.iter().map(|s| s.to_string()));
+    let level = legal_mode.level.to_string();
+
+    column![
+        row![
+            pick_list(
+                Method::ALL,
+                Some(legal_mode.method),
+                Message::LegalMethodSelected
+            )
+            .width(95)
+            .style(pick_list_default),
+            pick_list(
+                EncounterType::ALL,
+                Some(legal_mode.encounter_type),
+                Message::LegalEncounterTypeSelected
+            )
+            .width(Length::Fill)
+            .style(pick_list_default),
+            text("Lv."),
+            text_input(&level, &level)
+                .on_input(Message::LegalLevelChanged)
+                .align_x(Horizontal::Center)
+                .style(text_input_default)
+                .width(35)
+                .size(12),
+        ]
+        .spacing(5)
+        .align_y(Alignment::Center),
+        row![
+            pick_list(locations, Some(location), |location| {
+                Message::LegalMetLocationSelected(location.id())
+            })
+            .width(Length::Fill)
+            .style(pick_list_default),
+            pick_list(
+                natures,
+                Some(
+                    legal_mode
+                        .nature
+                        .clone()
+                        .unwrap_or_else(|| String::from(ANY))
+                ),
+                Message::LegalNatureSelected
+            )
+            .width(90)
+            .style(pick_list_default),
+            button(text("★").shaping(text::Shaping::Advanced).center())
+                .on_press(Message::LegalShinyToggled)
+                .style(toggle_style(legal_mode.shiny)),
+        ]
+        .spacing(5)
+        .align_y(Alignment::Center),
+    ]
+    .spacing(5)
+    .padding([0, 15])
+    .into()
+}
+
 fn info_label(
     pokemon: &AnyPokemon,
     game_data: &AnyGameData,
@@ -239,7 +878,7 @@ fn info_label(
         .style(pokeball_picker_apperance)
     };
 
-    let pokerus = match pokemon.pokerus_status() {
+    let pokerus = match pokemon.pokerus().status() {
         Pokerus::None => image(images.get("PokérusIC_not_infected").unwrap_or({
             let width = 10;
             let height = 10;
@@ -273,14 +912,57 @@ fn info_label(
         .interaction(iced::mouse::Interaction::Pointer)
         .on_press(Message::ChangePokerusStatus);
 
-    let name = text(pokemon.nickname()).shaping(text::Shaping::Advanced);
+    let shiny = mouse_area(
+        text(if pokemon.is_shiny() { "★" } else { "☆" })
+            .shaping(text::Shaping::Advanced)
+            .color(if pokemon.is_shiny() {
+                color!(0xffcc00)
+            } else {
+                color!(0x808080)
+            })
+            .size(22),
+    )
+    .interaction(iced::mouse::Interaction::Pointer)
+    .on_press(Message::ShinyToggled);
+
+    let name = text_input(&pokemon.nickname(), &pokemon.nickname())
+        .on_input(Message::NicknameChanged)
+        .on_paste(Message::NicknameChanged)
+        .on_submit(Message::NicknameSubmitted)
+        .line_height(text::LineHeight::Absolute(16.into()))
+        .style(nickname_input_appearance)
+        .width(120)
+        .size(16);
+
+    let glyphs = dropdown_root(
+        text("♂♀…").shaping(text::Shaping::Advanced),
+        row(NICKNAME_GLYPHS
+            .iter()
+            .filter(|glyph| pokemon.can_encode_char(**glyph))
+            .map(|glyph| {
+                button(
+                    text(glyph.to_string())
+                        .shaping(text::Shaping::Advanced)
+                        .center(),
+                )
+                .on_press(Message::NicknameGlyphSelected(*glyph))
+                .width(30)
+                .style(button::subtle)
+                .into()
+            }))
+        .spacing(2),
+    )
+    .overlay_width(Length::Shrink)
+    .style(pokeball_picker_apperance);
 
     let row = row![
         pokeball,
         name,
+        glyphs,
         iced::widget::Space::new().width(Length::Fill),
+        shiny,
         pokerus,
-        iced::widget::Space::new().width(25),
+        iced::widget::Space::new().width(15),
         input_level(pokemon.level()),
         gender(pokemon.gender()),
     ]
@@ -454,6 +1136,43 @@ fn stats(
     container(column).width(w(width)).into()
 }
 
+fn legality_badge(report: &LegalityReport) -> Element<'static, Message> {
+    let findings = if report.findings.is_empty() {
+        column![text("No issues found")]
+    } else {
+        column(report.findings.iter().map(|finding| {
+            let (label, colour) = match finding.severity {
+                Severity::Invalid => ("Invalid", color!(0xd65c63)),
+                Severity::Fishy => ("Fishy", color!(0xffcc00)),
+            };
+            row![
+                text(label).color(colour).width(60),
+                text(finding.message.clone()).shaping(text::Shaping::Advanced),
+            ]
+            .spacing(10)
+            .into()
+        }))
+    };
+    let findings = findings.spacing(5).padding(10);
+
+    let (label, style): (_, fn(&iced::Theme, button::Status) -> button::Style) =
+        if report.is_legal() {
+            ("Legal", legal_badge_appearance)
+        } else {
+            ("Illegal", illegal_badge_appearance)
+        };
+
+    dropdown_root(
+        text(label).size(12).center(),
+        container(findings).style(default_box).max_width(320),
+    )
+    .width(60)
+    .height(26)
+    .overlay_width(Length::Shrink)
+    .style(style)
+    .into()
+}
+
 fn pokemon_info_typing(
     typing: Option<(String, Option<String>)>,
     images: &HashMap<String, image::Handle>,
@@ -519,8 +1238,371 @@ fn pokemon_info_typing(
     }
 }
 
+fn experience(
+    pokemon: &AnyPokemon,
+    experience_input: &Option<String>,
+    game_data: &AnyGameData,
+    width: f32,
+) -> Element<'static, Message> {
+    let dex_number = pokemon.nat_dex_number();
+    let level = pokemon.level();
+    let experience = pokemon.experience();
+    let growth_rate = game_data
+        .growth_rate(dex_number)
+        .map(|growth_rate| growth_rate.to_string())
+        .unwrap_or_default();
+
+    let current = game_data.exp_for_level(dex_number, level);
+    let (to_next, progress) = if level >= 100 {
+        (String::from("Max level"), 1.0)
+    } else {
+        let next = game_data.exp_for_level(dex_number, level + 1);
+        let gained = experience.saturating_sub(current);
+        let needed = next.saturating_sub(current).max(1);
+        // Per mille keeps the ratio exact enough without lossy float casts.
+        let permille = u16::try_from(u64::from(gained) * 1000 / u64::from(needed)).unwrap_or(1000);
+        (
+            format!("{} to Lv. {}", next.saturating_sub(experience), level + 1),
+            f32::from(permille) / 1000.0,
+        )
+    };
+    let value = experience_input
+        .clone()
+        .unwrap_or_else(|| experience.to_string());
+
+    container(
+        column![
+            row![
+                text("Exp.").color(color!(0xffcc00)),
+                text_input(&value, &value)
+                    .on_input(Message::ExperienceChanged)
+                    .on_submit(Message::ExperienceSubmitted)
+                    .align_x(Horizontal::Center)
+                    .style(text_input_default)
+                    .line_height(text::LineHeight::Absolute(10.into()))
+                    .width(90)
+                    .size(12),
+                text(growth_rate).size(12),
+                iced::widget::Space::new().width(Length::Fill),
+                text(to_next).size(12),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+            exp_bar(width - 30.0, progress),
+        ]
+        .spacing(5),
+    )
+    .width(w(width))
+    .padding([5, 15])
+    .into()
+}
+
+/// Why a Pokérus byte can't come from the game, if it can't.
+fn pokerus_warning(pokerus: PokerusState) -> Option<String> {
+    if pokerus.strain == 0 && pokerus.days > 0 {
+        Some(String::from(
+            "Strain 0 never occurs in-game and behaves inconsistently",
+        ))
+    } else if pokerus.days > pokerus.max_days() {
+        Some(format!(
+            "Strain {} lasts at most {} days",
+            pokerus.strain,
+            pokerus.max_days()
+        ))
+    } else {
+        None
+    }
+}
+
+fn pokerus_panel<'a>(pokemon: &AnyPokemon, width: f32) -> Element<'a, Message> {
+    let pokerus = pokemon.pokerus();
+    let strain = pokerus.strain.to_string();
+    let days = pokerus.days.to_string();
+    let status = match pokerus.status() {
+        Pokerus::None => "Not infected",
+        Pokerus::Infected => "Infected",
+        Pokerus::Cured => "Cured",
+    };
+
+    let mut column = column![row![
+        text("Pokérus").color(color!(0xffcc00)),
+        text("Strain").size(12),
+        text_input(&strain, &strain)
+            .on_input(Message::PokerusStrainChanged)
+            .align_x(Horizontal::Center)
+            .style(text_input_default)
+            .line_height(text::LineHeight::Absolute(10.into()))
+            .width(30)
+            .size(12),
+        text("Days").size(12),
+        text_input(&days, &days)
+            .on_input(Message::PokerusDaysChanged)
+            .align_x(Horizontal::Center)
+            .style(text_input_default)
+            .line_height(text::LineHeight::Absolute(10.into()))
+            .width(30)
+            .size(12),
+        iced::widget::Space::new().width(Length::Fill),
+        text(status).size(12),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center)]
+    .spacing(5);
+
+    if let Some(warning) = pokerus_warning(pokerus) {
+        column = column.push(text(warning).size(12).color(color!(0xff7f0f)));
+    }
+
+    container(column).width(w(width)).padding([5, 15]).into()
+}
+
+fn battle<'a>(pokemon: &AnyPokemon, width: f32) -> Element<'a, Message> {
+    // Boxed Pokémon have no party data to edit.
+    let (Some(status), Some(current_hp)) = (pokemon.status(), pokemon.current_hp()) else {
+        return row![].into();
+    };
+    let hp = current_hp.to_string();
+    let sleep_turns = pokemon.sleep_turns().to_string();
+
+    let mut status_row = row![
+        text("Status").color(color!(0xffcc00)),
+        pick_list(Status::ALL, Some(status), Message::StatusSelected)
+            .width(130)
+            .style(pick_list_default),
+    ]
+    .spacing(10)
+    .align_y(Alignment::Center);
+    if status == Status::Asleep {
+        status_row = status_row.push(
+            text_input(&sleep_turns, &sleep_turns)
+                .on_input(Message::SleepTurnsChanged)
+                .align_x(Horizontal::Center)
+                .style(text_input_default)
+                .line_height(text::LineHeight::Absolute(10.into()))
+                .width(30)
+                .size(12),
+        );
+        status_row = status_row.push(text("turns").size(12));
+    }
+
+    container(
+        column![
+            status_row,
+            row![
+                text("HP").color(color!(0xffcc00)),
+                text_input(&hp, &hp)
+                    .on_input(Message::CurrentHpChanged)
+                    .align_x(Horizontal::Center)
+                    .style(text_input_default)
+                    .line_height(text::LineHeight::Absolute(10.into()))
+                    .width(45)
+                    .size(12),
+                text(format!("/ {}", pokemon.computed_stats().hp)),
+                iced::widget::Space::new().width(Length::Fill),
+                button(text("Recompute stats").size(12))
+                    .on_press(Message::RecomputeStats)
+                    .style(button::secondary),
+            ]
+            .spacing(10)
+            .align_y(Alignment::Center),
+        ]
+        .spacing(5),
+    )
+    .width(w(width))
+    .padding([5, 15])
+    .into()
+}
+
+/// The in-game summary memo, e.g. "Met in Route 101 at Lv. 5."
+fn memo(pokemon: &AnyPokemon, location: &str) -> String {
+    let memo = if pokemon.met_level() == 0 {
+        format!("Hatched in {location}.")
+    } else {
+        format!("Met in {location} at Lv. {}.", pokemon.met_level())
+    };
+    if pokemon.fateful_encounter() {
+        format!("{memo} Apparently had a fateful encounter.")
+    } else {
+        memo
+    }
+}
+
+fn origin(pokemon: &AnyPokemon, game_data: &AnyGameData, width: f32) -> Element<'static, Message> {
+    let locations = met_locations(game_data, pokemon.origin_game());
+    let location = locations
+        .iter()
+        .find(|location| location.id == pokemon.met_location())
+        .cloned()
+        .unwrap_or_else(|| MetLocation {
+            id: pokemon.met_location(),
+            name: format!("Location {}", pokemon.met_location()),
+        });
+    let met_level = pokemon.met_level().to_string();
+    let markings = pokemon.markings();
+
+    let mut marking_row = row![].spacing(2).align_y(Alignment::Center);
+    for (i, glyph) in MARKINGS.into_iter().enumerate() {
+        let marked = markings.get(i).copied().unwrap_or(false);
+        marking_row = marking_row.push(
+            mouse_area(
+                text(glyph)
+                    .shaping(text::Shaping::Advanced)
+                    .color(if marked {
+                        color!(0x4186d7)
+                    } else {
+                        color!(0x808080)
+                    })
+                    .size(16),
+            )
+            .interaction(iced::mouse::Interaction::Pointer)
+            .on_press(Message::MarkingToggled(i)),
+        );
+    }
+
+    container(
+        column![
+            row![
+                text("Origin").color(color!(0xffcc00)),
+                iced::widget::Space::new().width(Length::Fill),
+                pick_list(
+                    GameVersion::ALL,
+                    Some(pokemon.origin_game()),
+                    Message::OriginGameSelected
+                )
+                .width(130)
+                .style(pick_list_default),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+            row![
+                pick_list(locations, Some(location.clone()), |location| {
+                    Message::MetLocationSelected(location.id)
+                })
+                .width(Length::Fill)
+                .style(pick_list_default),
+                text("Lv."),
+                text_input(&met_level, &met_level)
+                    .on_input(Message::MetLevelChanged)
+                    .align_x(Horizontal::Center)
+                    .style(text_input_default)
+                    .line_height(text::LineHeight::Absolute(10.into()))
+                    .width(35)
+                    .size(12),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+            row![
+                button(text("Fateful encounter").size(12))
+                    .on_press(Message::FatefulEncounterToggled)
+                    .style(toggle_style(pokemon.fateful_encounter())),
+                iced::widget::Space::new().width(Length::Fill),
+                marking_row,
+            ]
+            .align_y(Alignment::Center),
+            text(memo(pokemon, &location.name)).size(12),
+        ]
+        .spacing(5),
+    )
+    .width(w(width))
+    .padding([5, 15])
+    .into()
+}
+
+fn contest(
+    pokemon: &AnyPokemon,
+    images: &HashMap<String, image::Handle>,
+    width: f32,
+) -> Element<'static, Message> {
+    let contest_stats = pokemon.contest_stats();
+    let values = [
+        contest_stats.cool,
+        contest_stats.beauty,
+        contest_stats.cute,
+        contest_stats.smart,
+        contest_stats.tough,
+        contest_stats.sheen,
+    ];
+
+    let mut stat_row = row![].spacing(5).align_y(Alignment::Center);
+    for (name, value) in CONTEST_STATS.into_iter().zip(values) {
+        let value = value.to_string();
+        stat_row = stat_row.push(
+            column![
+                text(name).size(12),
+                text_input(&value, &value)
+                    .on_input(move |input| Message::ContestStatChanged(String::from(name), input))
+                    .align_x(Horizontal::Center)
+                    .line_height(text::LineHeight::Absolute(10.into()))
+                    .width(40)
+                    .size(12),
+            ]
+            .align_x(Alignment::Center)
+            .spacing(2),
+        );
+    }
+
+    let mut ribbons = column![].spacing(2);
+    for (row_index, chunk) in Ribbon::ALL.chunks(8).enumerate() {
+        let mut ribbon_row = row![].spacing(2);
+        for (column_index, ribbon) in chunk.iter().enumerate() {
+            let icon = image(
+                images
+                    .get(&format!("ribbon_{:02}", row_index * 8 + column_index))
+                    .unwrap_or({
+                        let width = 10;
+                        let height = 10;
+                        let size = (width * height) as usize;
+                        let pixels = vec![0u8; size * 4];
+                        &image::Handle::from_rgba(width, height, pixels)
+                    }),
+            )
+            .width(28)
+            .height(28)
+            .opacity(if pokemon.has_ribbon(*ribbon) {
+                1.0
+            } else {
+                0.25
+            });
+            ribbon_row = ribbon_row.push(tooltip(
+                button(icon)
+                    .on_press(Message::RibbonToggled(*ribbon))
+                    .padding(2)
+                    .style(button::subtle),
+                container(text(ribbon.to_string()).size(12))
+                    .padding(5)
+                    .style(default_box),
+                tooltip::Position::Top,
+            ));
+        }
+        ribbons = ribbons.push(ribbon_row);
+    }
+
+    container(
+        column![
+            row![
+                text("Contest").color(color!(0xffcc00)),
+                iced::widget::Space::new().width(Length::Fill),
+                button(text("Grant legal").size(12))
+                    .on_press(Message::GrantLegalRibbons)
+                    .style(button::secondary),
+                button(text("Clear").size(12))
+                    .on_press(Message::ClearRibbons)
+                    .style(button::secondary),
+            ]
+            .spacing(5)
+            .align_y(Alignment::Center),
+            stat_row,
+            ribbons,
+        ]
+        .spacing(10),
+    )
+    .width(w(width))
+    .padding([5, 15])
+    .into()
+}
+
 fn info_moves(
-    moves: Vec<(String, String, u8, u8)>,
+    moves: Vec<Move>,
     all_moves: Vec<String>,
     images: &HashMap<String, image::Handle>,
     width: f32,
@@ -528,23 +1610,20 @@ fn info_moves(
     let mut column = column![];
     let len = moves.len();
 
-    for (index, (typing, name, pp_used, pp)) in moves.into_iter().enumerate() {
-        column = column.push(move_slot(
-            index,
-            &typing,
-            &name,
-            pp_used,
-            pp,
-            all_moves.clone(),
-            images,
-        ));
+    for (index, pokemon_move) in moves.iter().enumerate() {
+        column = column.push(move_slot(index, pokemon_move, all_moves.clone(), images));
     }
 
     for i in 0..4_u8.saturating_sub(u8::try_from(len).unwrap_or(0)) {
         let position = i as usize + len;
-        let row = row![button(text("+").center())
-            .on_press(Message::AddMove(position))
-            .width(width),]
+        let row = row![
+            pick_list(all_moves.clone(), None::<String>, move |selection| {
+                Message::MoveSelected(position, selection)
+            })
+            .placeholder("+ Add move")
+            .width(width)
+            .style(pick_list_default),
+        ]
         .width(w(width))
         .padding([5, 15])
         .align_y(Alignment::Center);
@@ -562,7 +1641,10 @@ fn info_moves(
 
 pub fn pokemon_info<'a>(
     state: &'a iced::widget::combo_box::State<String>,
+    legal_mode: &LegalMode,
+    experience_input: &Option<String>,
     pokemon: &AnyPokemon,
+    legality: Option<&LegalityReport>,
     game_data: &AnyGameData,
     images: &HashMap<String, image::Handle>,
     scale: f32,
@@ -580,9 +1662,15 @@ pub fn pokemon_info<'a>(
         )
         .width(130)
         .input_style(text_input_default),
+        button(text("Legal").size(12))
+            .on_press(Message::LegalModeToggled)
+            .style(toggle_style(legal_mode.enabled)),
         iced::widget::Space::new().width(Length::Fill),
         text(pokemon.language()),
-        iced::widget::Space::new().width(45),
+        match legality {
+            Some(report) => legality_badge(report),
+            None => iced::widget::Space::new().into(),
+        },
     ]
     .spacing(20)
     .align_y(Alignment::Center)
@@ -593,12 +1681,27 @@ pub fn pokemon_info<'a>(
         left: 15.0,
     }); // top, right, bottom, left
 
-    let ot = container(row![row![
-        text("OT Name").color(color!(0xffcc00)),
-        iced::widget::Space::new().width(10),
-        text(pokemon.ot_name()),
-    ]
-    .width(width / 2.0),])
+    let ot_gender = match pokemon.ot_gender() {
+        Gender::F => text("♀").color(color!(0xd65c63)),
+        Gender::M | Gender::None => text("♂").color(color!(0x4186d7)),
+    };
+
+    let ot = container(row![
+        row![
+            text("OT Name").color(color!(0xffcc00)),
+            iced::widget::Space::new().width(10),
+            text(pokemon.ot_name()),
+        ]
+        .width(width / 2.0),
+        row![
+            text("OT Gender").color(color!(0xffcc00)),
+            iced::widget::Space::new().width(Length::Fill),
+            mouse_area(ot_gender.shaping(text::Shaping::Advanced))
+                .interaction(iced::mouse::Interaction::Pointer)
+                .on_press(Message::OtGenderToggled),
+        ]
+        .width(width / 2.0),
+    ])
     .width(w(width))
     .height(40.0)
     .align_y(iced::alignment::Vertical::Center)
@@ -614,19 +1717,35 @@ pub fn pokemon_info<'a>(
         ]
         .width(width / 2.0)
         .align_y(Alignment::Center),
-        row![
-            text("Friendship").color(color!(0xffcc00)),
-            iced::widget::Space::new().width(Length::Fill),
-            text_input(
-                &pokemon.friendship().to_string(),
-                &pokemon.friendship().to_string()
-            )
-            .align_x(Horizontal::Center)
-            .on_input(Message::FriendshipChanged)
-            .line_height(text::LineHeight::Absolute(10.into()))
-            .size(12),
-        ]
-        .width(width / 2.0),
+        if pokemon.is_egg() {
+            row![
+                text("Egg cycles").color(color!(0xffcc00)),
+                iced::widget::Space::new().width(Length::Fill),
+                text_input(
+                    &pokemon.egg_cycles().to_string(),
+                    &pokemon.egg_cycles().to_string()
+                )
+                .align_x(Horizontal::Center)
+                .on_input(Message::EggCyclesChanged)
+                .line_height(text::LineHeight::Absolute(10.into()))
+                .size(12),
+            ]
+            .width(width / 2.0)
+        } else {
+            row![
+                text("Friendship").color(color!(0xffcc00)),
+                iced::widget::Space::new().width(Length::Fill),
+                text_input(
+                    &pokemon.friendship().to_string(),
+                    &pokemon.friendship().to_string()
+                )
+                .align_x(Horizontal::Center)
+                .on_input(Message::FriendshipChanged)
+                .line_height(text::LineHeight::Absolute(10.into()))
+                .size(12),
+            ]
+            .width(width / 2.0)
+        },
     ])
     .width(w(width))
     .height(40.0)
@@ -697,21 +1816,21 @@ pub fn pokemon_info<'a>(
     .padding([5, 15]);
 
     let all_moves = game_data.moves().unwrap_or_default();
-    let moves = info_moves(
-        pokemon
-            .moves()
-            .into_iter()
-            .map(|m| (m.move_type, m.name, m.pp_used, m.pp))
-            .collect(),
-        all_moves,
-        images,
-        width,
-    );
-
-    container(column![
+    let moves = info_moves(pokemon.moves(), all_moves, images, width);
+
+    let legal_settings: Element<'a, Message> = if legal_mode.enabled {
+        legal_settings(legal_mode, game_data)
+    } else {
+        row![].into()
+    };
+
+    container(scrollable(column![
         label,
         dex_species_lang,
+        form(pokemon, game_data),
+        legal_settings,
         pokemon_info_typing(pokemon.typing(), images, width),
+        experience(pokemon, experience_input, game_data, width),
         iced::widget::Space::new().width(10),
         stats(
             pokemon.computed_stats(),
@@ -719,14 +1838,18 @@ pub fn pokemon_info<'a>(
             pokemon.evs(),
             width
         ),
+        battle(pokemon, width),
+        pokerus_panel(pokemon, width),
         iced::widget::Space::new().width(10),
         ot,
+        origin(pokemon, game_data, width),
         pid_friendship,
         nature_ability,
         item,
         moves,
+        contest(pokemon, images, width),
         row![].height(30)
-    ])
+    ]))
     .width(w(width))
     .height(iced::Length::Fill)
     .style(pokemon_info_appearance)
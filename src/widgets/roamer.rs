@@ -0,0 +1,171 @@
+//! Composite widget rendering the roaming legendary.
+//!
+//! Displays the roamer's sprite, species, level, current HP, status and
+//! location like a party Pokémon, with editable level, PID and IVs, plus
+//! **Reactivate** (full HP, no status, roaming again) and **Reset** (clear the
+//! roamer so the game rolls a new one) actions for redoing shiny hunts.
+//!
+//! Owns its own [`Message`] enum and [`update`] function which write the
+//! [`Roamer`] back through [`Gen3SaveFile::save_roamer`].
+
+use iced::advanced::image;
+use iced::alignment::Horizontal;
+use iced::color;
+use iced::widget::{button, column, container, row, text, text_input};
+use iced::{Alignment, Element, Length, Padding};
+use pk_edit::error::SaveDataError;
+
+use std::collections::HashMap;
+
+use crate::{shadow_box_light, tab_bar_button_primary, text_input_default};
+
+use pk_edit::{Gen3SaveFile, Roamer, Status};
+
+const IV_NAMES: [&str; 6] = ["HP", "Attack", "Defense", "Sp. Atk", "Sp. Def", "Speed"];
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    LevelChanged(String),
+    PidChanged(String),
+    IVChanged(String, String),
+    Reactivate,
+    Reset,
+}
+
+pub fn update(
+    roamer: &mut Roamer,
+    save_file: &mut Gen3SaveFile,
+    message: Message,
+) -> Result<(), SaveDataError> {
+    match message {
+        Message::LevelChanged(mut value) => {
+            value.retain(|c| c.is_ascii_digit());
+            roamer.level = value.parse::<u8>().unwrap_or(1).clamp(1, 100);
+        }
+        Message::PidChanged(mut value) => {
+            value.retain(|c| c.is_ascii_hexdigit());
+            if value.is_empty() {
+                roamer.personality_value = 0;
+            } else if let Ok(pid) = u32::from_str_radix(&value, 16) {
+                roamer.personality_value = pid;
+            }
+        }
+        Message::IVChanged(iv, mut value) => {
+            value.retain(|c| c.is_numeric());
+            if let Ok(number) = value.parse::<u16>() {
+                roamer.update_iv(&iv, number);
+            } else if value.is_empty() {
+                roamer.update_iv(&iv, 0);
+            }
+        }
+        Message::Reactivate => {
+            roamer.reactivate();
+        }
+        Message::Reset => {
+            save_file.reset_roamer()?;
+            *roamer = save_file.roamer()?;
+            return Ok(());
+        }
+    }
+    save_file.save_roamer(roamer)
+}
+
+pub fn roamer<'a>(
+    roamer: &Roamer,
+    images: &HashMap<String, image::Handle>,
+) -> Element<'a, Message> {
+    let sprite = iced::widget::image(
+        images
+            .get(&format!("{:0width$}", roamer.nat_dex_number, width = 4))
+            .unwrap_or({
+                let width = 10;
+                let height = 10;
+                let size = (width * height) as usize;
+                let pixels = vec![0u8; size * 4];
+                &image::Handle::from_rgba(width, height, pixels)
+            }),
+    )
+    .height(80);
+
+    let level = roamer.level.to_string();
+    let pid = format!("{:X}", roamer.personality_value);
+    let ivs = [
+        roamer.ivs.hp,
+        roamer.ivs.attack,
+        roamer.ivs.defense,
+        roamer.ivs.special_attack,
+        roamer.ivs.special_defense,
+        roamer.ivs.speed,
+    ];
+
+    let mut iv_row = row![text("IVs").color(color!(0xffcc00)).width(60)]
+        .align_y(Alignment::Center)
+        .spacing(5);
+    for (name, value) in IV_NAMES.into_iter().zip(ivs) {
+        let value = value.to_string();
+        iv_row = iv_row.push(
+            column![
+                text(name).size(12),
+                text_input(&value, &value)
+                    .on_input(move |input| Message::IVChanged(String::from(name), input))
+                    .align_x(Horizontal::Center)
+                    .style(text_input_default)
+                    .width(45)
+                    .size(12),
+            ]
+            .align_x(Alignment::Center)
+            .spacing(2),
+        );
+    }
+
+    container(
+        row![
+            sprite,
+            column![
+                row![
+                    text(roamer.species.clone()),
+                    text(if roamer.active { "Roaming" } else { "Inactive" })
+                        .color(color!(0xffcc00)),
+                    iced::widget::Space::new().width(Length::Fill),
+                    button(text("Reactivate"))
+                        .on_press(Message::Reactivate)
+                        .style(tab_bar_button_primary),
+                    button(text("Reset"))
+                        .on_press(Message::Reset)
+                        .style(tab_bar_button_primary),
+                ]
+                .align_y(Alignment::Center)
+                .spacing(10),
+                row![
+                    text("Lv."),
+                    text_input(&level, &level)
+                        .on_input(Message::LevelChanged)
+                        .align_x(Horizontal::Center)
+                        .style(text_input_default)
+                        .width(45)
+                        .size(12),
+                    text("PID"),
+                    text_input(&pid, &pid)
+                        .on_input(Message::PidChanged)
+                        .align_x(Horizontal::Center)
+                        .style(text_input_default)
+                        .width(90)
+                        .size(12),
+                    text(format!("HP {}", roamer.hp)),
+                    text(Status::from_bits(roamer.status).to_string()),
+                    text(roamer.location.clone()),
+                ]
+                .align_y(Alignment::Center)
+                .spacing(10),
+                iv_row,
+            ]
+            .spacing(10),
+        ]
+        .padding(Padding::from([5, 10]))
+        .align_y(Alignment::Center)
+        .spacing(15),
+    )
+    .style(shadow_box_light)
+    .width(1000)
+    .into()
+}
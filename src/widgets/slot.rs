@@ -4,6 +4,8 @@
 //! - [`pc_slot`] — 80 × 80, displays the Pokémon sprite only.
 //! - [`party_slot`] — 240 × 80, displays sprite, level badge, nickname, and gender badge.
 //!
+//! Both kinds draw a gold dot in the top-right corner for shiny Pokémon.
+//!
 //! Interaction (hover, press, select, drag) and styling are implemented once.
 //! Layout and draw branch on [`SlotKind`].
 
@@ -108,6 +110,7 @@ where
     on_drag_start: Option<Box<dyn Fn(Point) -> Message + 'a>>,
     in_drag_mode: bool,
     is_drag_source: bool,
+    is_shiny: bool,
     children: Vec<Element<'a, Message, Theme, Renderer>>,
 }
 
@@ -148,6 +151,7 @@ where
             on_drag_start: None,
             in_drag_mode: false,
             is_drag_source: false,
+            is_shiny: false,
             is_selected: false,
             status: Some(Status::Idle),
             class: <Theme as Catalog>::default(),
@@ -175,6 +179,12 @@ where
         self
     }
 
+    /// Draws the shiny indicator when `v` is true.
+    pub fn shiny(mut self, v: bool) -> Self {
+        self.is_shiny = v;
+        self
+    }
+
     /// Sets a message to publish when the slot is clicked.
     pub fn on_press(mut self, on_press: Message) -> Self {
         self.on_press = Some(OnPress::Direct(on_press));
@@ -373,6 +383,27 @@ where
                 }
             }
         }
+
+        if self.is_shiny {
+            let size = 10.0 * self.scale;
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + bounds.width - size - 8.0 * self.scale,
+                        y: bounds.y + 8.0 * self.scale,
+                        width: size,
+                        height: size,
+                    },
+                    border: Border {
+                        radius: (size / 2.0).into(),
+                        width: 0.0,
+                        color: Color::TRANSPARENT,
+                    },
+                    ..Default::default()
+                },
+                color!(0xffcc00),
+            );
+        }
     }
 
     fn mouse_interaction(